@@ -2,73 +2,100 @@ use darling::{
     util::{Flag, SpannedValue},
     FromMeta,
 };
-use proc_macro_error::abort;
-use syn::ExprClosure;
 
-#[derive(Debug, Clone, FromMeta)]
+#[derive(Debug, Clone, Default, FromMeta)]
 #[non_exhaustive]
 pub(crate) struct ImplNewAttr {
     pub name: Option<SpannedValue<String>>,
-    pub default: Flag,
-    pub value: Option<SpannedValue<syn::Expr>>,
+    pub default: Option<SpannedValue<DefaultAttr>>,
+    pub value: Option<SpannedValue<ValueAttr>>,
+    pub into: Option<SpannedValue<bool>>,
+    pub try_into: Flag,
+    pub validate: Option<SpannedValue<syn::Path>>,
+    pub optional: Option<SpannedValue<OptionalMode>>,
+    pub with: Flag,
+    pub get: Flag,
+    pub get_mut: Flag,
+    pub get_copy: Flag,
+    pub get_clone: Flag,
 }
 
 impl ImplNewAttr {
     /// Returns the supported options for the `impl_new` attribute.
     pub(crate) const fn supported_options() -> &'static [&'static str] {
-        &["`name = \"field_name\"`", "value = || <VALUE>", "`default`"]
+        &[
+            "`name = \"field_name\"`",
+            "`value = <VALUE>`",
+            "`default`",
+            "`default = <VALUE>`",
+            "`into = false`",
+            "`try_into`",
+            "`validate = path::to::validator`",
+            "`optional`",
+            "`optional = \"arg\"`",
+            "`with`",
+            "`get`",
+            "`get_mut`",
+            "`get_copy`",
+            "`get_clone`",
+        ]
     }
+}
+
+/// The `default` option of the field `impl_new` attribute.
+#[derive(Debug, Clone)]
+pub(crate) struct DefaultAttr {
+    /// The default value of the field, None if it's `Default::default()`.
+    pub value: Option<syn::Expr>,
+}
 
-    /// Merges the attributes. Will abort if there a duplicates.
-    pub(crate) fn merge(&mut self, others: &[Self]) {
-        /// Checks if there are duplicates. Will abort if there are.
-        macro_rules! check_dup {
-        ($field:ident) => {
-            let mut attrs = vec![self.clone()];
-            attrs.extend_from_slice(others);
-            let attrs = attrs.into_iter().filter_map(|attr| attr.$field.clone());
-            if attrs.clone().count() > 1 {
-                abort!(
-                    attrs.last().unwrap().span(),
-                    "Duplicate `impl_new` attribute for `{}`.",
-                    stringify!($field);
-                    help = "Remove the duplicate attributes."
-                )
-            }
-        };
-        ($($field:ident),*) => {
-            $(check_dup!($field);)*
-        };
+impl FromMeta for DefaultAttr {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self { value: None })
     }
-        /// Merges the attributes options.\
-        macro_rules! merge_opts {
-        ($field:ident) => {
-            let opt = others.iter().find_map(|attr| {
-                if let Some(ref opt) = attr.$field {
-                    Some(opt.clone())
-                } else {
-                    None
-                }
-            });
 
-            if self.$field.is_none() {
-                if let Some(opt) = opt {
-                    self.$field = Some(opt);
-                }
-            }
-        };
-        ($($field:ident),*) => {
-            $(merge_opts!($field);)*
-        };
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(Self {
+            value: Some(expr.clone()),
+        })
     }
-        check_dup!(name);
-        merge_opts!(name);
+}
+
+/// The `value` option of the field `impl_new` attribute.
+#[derive(Debug, Clone)]
+pub(crate) struct ValueAttr {
+    /// The value of the field, as it's written, a string literal is not parsed as an expression.
+    pub value: syn::Expr,
+}
+
+impl FromMeta for ValueAttr {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(Self {
+            value: expr.clone(),
+        })
     }
 }
 
-fn check_is_closure(expr: syn::Expr) -> ExprClosure {
-    match expr {
-        syn::Expr::Closure(closure) => closure,
-        _ => abort!(expr, "Expected a closure."),
+/// The `optional` option of the field and struct `impl_new` attributes, for `Option<T>` fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OptionalMode {
+    /// `optional` or `optional = "omit"`, the field is removed from the arguments and set to `None`.
+    Omit,
+    /// `optional = "arg"`, the argument is `impl Into<Option<T>>`, so it can be `T` or `None`.
+    /// It's the default argument type, so this mode only matters with the `into = false` option.
+    Arg,
+}
+
+impl FromMeta for OptionalMode {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Omit)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "omit" => Ok(Self::Omit),
+            "arg" => Ok(Self::Arg),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
     }
 }
@@ -0,0 +1,143 @@
+use darling::{
+    util::{Flag, PathList, SpannedValue},
+    FromDeriveInput, FromMeta,
+};
+
+use super::OptionalMode;
+use proc_macro2::{Ident, TokenStream, TokenTree};
+
+/// The `#[impl_new(...)]` attribute on the struct itself, it configures the generated function as a whole.
+#[derive(Debug, Clone, Default, FromDeriveInput)]
+#[darling(attributes(impl_new), default)]
+#[non_exhaustive]
+pub(crate) struct ImplNewContainerAttr {
+    #[darling(rename = "vis")]
+    pub visibility: Option<syn::Visibility>,
+    pub fn_name: Option<SpannedValue<String>>,
+    #[darling(multiple, rename = "constructor")]
+    pub constructors: Vec<SpannedValue<ConstructorAttr>>,
+    pub into: Option<SpannedValue<bool>>,
+    /// The `const` keyword can't be parsed as a meta item, it's replaced with `const_` before parsing.
+    #[darling(rename = "const_")]
+    pub is_const: Flag,
+    pub try_new: Flag,
+    pub error: Option<SpannedValue<syn::Type>>,
+    pub validate: Option<SpannedValue<syn::Path>>,
+    pub validate_all: Flag,
+    pub builder: Flag,
+    pub from_tuple: Flag,
+    pub from_arg: Flag,
+    pub impl_default: Flag,
+    pub optional: Option<SpannedValue<OptionalMode>>,
+    pub with: Flag,
+    pub get: Flag,
+    pub get_mut: Flag,
+    pub get_copy: Flag,
+    pub get_clone: Flag,
+    pub doc: Option<SpannedValue<String>>,
+    pub doc_append: Option<SpannedValue<String>>,
+    pub fn_attrs: Option<SpannedValue<FnAttrs>>,
+    pub must_use: Option<SpannedValue<bool>>,
+}
+
+impl ImplNewContainerAttr {
+    /// Parses the struct `impl_new` attributes.
+    pub(crate) fn parse(ast: &syn::DeriveInput) -> darling::Result<Self> {
+        let mut ast = ast.clone();
+        for attr in ast.attrs.iter_mut() {
+            if let syn::Meta::List(ref mut meta_list) = attr.meta {
+                if meta_list.path.is_ident("impl_new") {
+                    let mut tokens = Vec::new();
+                    for token in meta_list.tokens.clone() {
+                        match token {
+                            // `const_` is the internal name of the `const` option, it's not an option itself.
+                            TokenTree::Ident(ident) if ident == "const_" => {
+                                return Err(
+                                    darling::Error::unknown_field("const_").with_span(&ident)
+                                );
+                            }
+                            TokenTree::Ident(ident) if ident == "const" => {
+                                tokens.push(TokenTree::Ident(Ident::new("const_", ident.span())));
+                            }
+                            token => tokens.push(token),
+                        }
+                    }
+                    meta_list.tokens = tokens.into_iter().collect::<TokenStream>();
+                }
+            }
+        }
+        // The errors mention the `const` option by its internal name, they are renamed back.
+        Self::from_derive_input(&ast).map_err(|errors| {
+            darling::Error::multiple(
+                errors
+                    .into_iter()
+                    .map(|err| {
+                        let span = Ident::new("const", err.span());
+                        darling::Error::custom(err.to_string().replace("`const_`", "`const`"))
+                            .with_span(&span)
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    /// Returns the supported options for the struct `impl_new` attribute.
+    pub(crate) const fn supported_options() -> &'static [&'static str] {
+        &[
+            "`vis = \"pub(crate)\"`",
+            "`fn_name = \"function_name\"`",
+            "`constructor(name = \"function_name\", args(field, ...))`",
+            "`into = false`",
+            "`const`",
+            "`try_new`",
+            "`error = \"ErrorType\"`",
+            "`validate = path::to::validator`",
+            "`validate_all`",
+            "`builder`",
+            "`from_tuple`",
+            "`from_arg`",
+            "`impl_default`",
+            "`optional`",
+            "`optional = \"arg\"`",
+            "`with`",
+            "`get`",
+            "`get_mut`",
+            "`get_copy`",
+            "`get_clone`",
+            "`doc = \"...\"`",
+            "`doc_append = \"...\"`",
+            "`fn_attrs(attr, ...)`",
+            "`must_use = false`",
+        ]
+    }
+}
+
+/// The `constructor(...)` option of the struct `impl_new` attribute, each one is a generated function.
+#[derive(Debug, Clone, FromMeta)]
+#[non_exhaustive]
+pub(crate) struct ConstructorAttr {
+    /// The name of the generated function.
+    pub name: SpannedValue<String>,
+    /// The fields that will be arguments of the function, in the same order.
+    /// If not set, all the fields will be arguments.
+    pub args: Option<PathList>,
+}
+
+/// The `fn_attrs(...)` option of the struct `impl_new` attribute, the attributes that are added to the generated constructors.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FnAttrs(pub Vec<syn::Meta>);
+
+impl FromMeta for FnAttrs {
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                darling::ast::NestedMeta::Meta(meta) => Ok(meta.clone()),
+                darling::ast::NestedMeta::Lit(lit) => {
+                    Err(darling::Error::unexpected_lit_type(lit).with_span(lit))
+                }
+            })
+            .collect::<darling::Result<_>>()
+            .map(Self)
+    }
+}
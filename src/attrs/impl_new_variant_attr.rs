@@ -0,0 +1,20 @@
+use darling::{
+    util::{Flag, SpannedValue},
+    FromVariant,
+};
+
+/// The `#[impl_new(...)]` attribute on an enum variant, it configures the generated function of the variant.
+#[derive(Debug, Clone, Default, FromVariant)]
+#[darling(attributes(impl_new), default)]
+#[non_exhaustive]
+pub(crate) struct ImplNewVariantAttr {
+    pub fn_name: Option<SpannedValue<String>>,
+    pub skip: Flag,
+}
+
+impl ImplNewVariantAttr {
+    /// Returns the supported options for the variant `impl_new` attribute.
+    pub(crate) const fn supported_options() -> &'static [&'static str] {
+        &["`fn_name = \"function_name\"`", "`skip`"]
+    }
+}
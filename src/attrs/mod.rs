@@ -1,2 +1,6 @@
 mod impl_new_attr;
+mod impl_new_container_attr;
+mod impl_new_variant_attr;
 pub(crate) use impl_new_attr::*;
+pub(crate) use impl_new_container_attr::*;
+pub(crate) use impl_new_variant_attr::*;
@@ -0,0 +1,290 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::new_struct::NewStruct;
+
+/// The builder state of a field.
+enum BuilderField {
+    /// A required field, with its typestate generic parameter.
+    Required(Ident),
+    /// An optional field, the field uses the `default` or `value` option.
+    Optional,
+}
+
+/// Returns the name of the builder of the given struct.
+fn builder_ident(new_struct: &NewStruct) -> Ident {
+    format_ident!("{}Builder", new_struct.ident)
+}
+
+/// Returns the builder state of each field.
+fn builder_fields(new_struct: &NewStruct) -> Vec<BuilderField> {
+    new_struct
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            if field.has_fallback() {
+                BuilderField::Optional
+            } else {
+                BuilderField::Required(format_ident!("__ImplNewState{}", idx))
+            }
+        })
+        .collect()
+}
+
+/// Returns the struct generics without the defaults, with the given type parameters appended.
+fn generics_with(new_struct: &NewStruct, params: &[&Ident]) -> syn::Generics {
+    let mut generics = new_struct.generics.clone();
+    for param in generics.params.iter_mut() {
+        match param {
+            syn::GenericParam::Type(ref mut type_param) => {
+                type_param.eq_token = None;
+                type_param.default = None;
+            }
+            syn::GenericParam::Const(ref mut const_param) => {
+                const_param.eq_token = None;
+                const_param.default = None;
+            }
+            syn::GenericParam::Lifetime(_) => {}
+        }
+    }
+    generics.params.extend(
+        params
+            .iter()
+            .map(|param| -> syn::GenericParam { syn::parse_quote!(#param) }),
+    );
+    generics
+}
+
+/// Returns the builder type, with the given types as the required fields states.
+fn builder_type(new_struct: &NewStruct, states: &[TokenStream]) -> TokenStream {
+    let builder_ident = builder_ident(new_struct);
+    let struct_args = new_struct.generics.params.iter().map(|param| match param {
+        syn::GenericParam::Lifetime(lifetime_param) => {
+            let lifetime = &lifetime_param.lifetime;
+            quote!(#lifetime)
+        }
+        syn::GenericParam::Type(type_param) => {
+            let ident = &type_param.ident;
+            quote!(#ident)
+        }
+        syn::GenericParam::Const(const_param) => {
+            let ident = &const_param.ident;
+            quote!(#ident)
+        }
+    });
+    quote!(#builder_ident<#(#struct_args,)* #(#states),*>)
+}
+
+/// Returns the builder field name of the field at the given index.
+fn slot(idx: usize) -> Ident {
+    format_ident!("__impl_new_{}", idx)
+}
+
+/// Generates the `builder` function of the struct.
+pub(crate) fn builder_function(new_struct: &NewStruct) -> TokenStream {
+    let builder_fields = builder_fields(new_struct);
+    let vis = new_struct.fn_vis();
+    let unset_states: Vec<TokenStream> = builder_fields
+        .iter()
+        .filter_map(|builder_field| match builder_field {
+            BuilderField::Required(_) => Some(quote!(())),
+            BuilderField::Optional => None,
+        })
+        .collect();
+    let builder_type = builder_type(new_struct, &unset_states);
+    let builder_ident = builder_ident(new_struct);
+    let slots = (0..builder_fields.len()).map(slot);
+    let initial_values = builder_fields
+        .iter()
+        .map(|builder_field| match builder_field {
+            BuilderField::Required(_) => quote!(()),
+            BuilderField::Optional => quote!(::core::option::Option::None),
+        });
+    let doc = format!(
+        " Creates a new [`{}`] builder, with no fields set.",
+        new_struct.ident
+    );
+
+    quote! {
+        #[doc = #doc]
+        #vis fn builder() -> #builder_type {
+            #builder_ident {
+                #(#slots: #initial_values,)*
+                __impl_new_marker: ::core::marker::PhantomData,
+            }
+        }
+    }
+}
+
+/// Generates the builder struct, its setters and its `build` function.
+pub(crate) fn builder(new_struct: &NewStruct) -> TokenStream {
+    let builder_fields = builder_fields(new_struct);
+    let builder_ident = builder_ident(new_struct);
+    let struct_ident = &new_struct.ident;
+    let struct_vis = &new_struct.vis;
+    let fn_vis = new_struct.fn_vis();
+    let all_states: Vec<&Ident> = builder_fields
+        .iter()
+        .filter_map(|builder_field| match builder_field {
+            BuilderField::Required(state) => Some(state),
+            BuilderField::Optional => None,
+        })
+        .collect();
+    let where_clause = &new_struct.generics.where_clause;
+    let (_, struct_ty_generics, _) = new_struct.generics.split_for_impl();
+
+    // The builder struct
+    let struct_generics = generics_with(new_struct, &all_states);
+    let (struct_params, _, _) = struct_generics.split_for_impl();
+    let slots_decl = new_struct
+        .fields
+        .iter()
+        .zip(&builder_fields)
+        .enumerate()
+        .map(|(idx, (field, builder_field))| {
+            let slot = slot(idx);
+            match builder_field {
+                BuilderField::Required(state) => quote!(#slot: #state),
+                BuilderField::Optional => {
+                    let ty = &field.ty;
+                    quote!(#slot: ::core::option::Option<#ty>)
+                }
+            }
+        });
+    let builder_doc = format!(
+        " A builder for [`{}`], created by [`{}::builder`].\n\n The `build` function is available once all the required fields are set.",
+        struct_ident, struct_ident
+    );
+
+    // The setters
+    let setters = new_struct
+        .fields
+        .iter()
+        .zip(&builder_fields)
+        .enumerate()
+        .filter_map(|(idx, (field, builder_field))| {
+            let param_name = field.param_name()?;
+            let arg_type = new_struct.arg_type(field);
+            let value = new_struct.arg_value(field);
+            let field_slot = slot(idx);
+            let doc = format!(" Sets the `{}` field.", param_name);
+            Some(match builder_field {
+                BuilderField::Required(state) => {
+                    let other_states: Vec<&Ident> = all_states
+                        .iter()
+                        .copied()
+                        .filter(|other| other != &state)
+                        .collect();
+                    let impl_generics = generics_with(new_struct, &other_states);
+                    let (impl_generics, _, _) = impl_generics.split_for_impl();
+                    let with_state = |set: TokenStream| -> Vec<TokenStream> {
+                        all_states
+                            .iter()
+                            .map(|other| {
+                                if other == &state {
+                                    set.clone()
+                                } else {
+                                    quote!(#other)
+                                }
+                            })
+                            .collect()
+                    };
+                    let ty = &field.ty;
+                    let self_type = builder_type(new_struct, &with_state(quote!(())));
+                    let return_type = builder_type(new_struct, &with_state(quote!((#ty,))));
+                    let other_slots: Vec<Ident> = (0..builder_fields.len())
+                        .filter(|other| *other != idx)
+                        .map(slot)
+                        .collect();
+                    quote! {
+                        impl #impl_generics #self_type #where_clause {
+                            #[doc = #doc]
+                            #fn_vis fn #param_name(self, #param_name: #arg_type) -> #return_type {
+                                #builder_ident {
+                                    #field_slot: (#value,),
+                                    #(#other_slots: self.#other_slots,)*
+                                    __impl_new_marker: ::core::marker::PhantomData,
+                                }
+                            }
+                        }
+                    }
+                }
+                BuilderField::Optional => {
+                    let (impl_generics, _, _) = struct_generics.split_for_impl();
+                    let states: Vec<TokenStream> =
+                        all_states.iter().map(|state| quote!(#state)).collect();
+                    let self_type = builder_type(new_struct, &states);
+                    quote! {
+                        impl #impl_generics #self_type #where_clause {
+                            #[doc = #doc]
+                            #fn_vis fn #param_name(mut self, #param_name: #arg_type) -> Self {
+                                self.#field_slot = ::core::option::Option::Some(#value);
+                                self
+                            }
+                        }
+                    }
+                }
+            })
+        });
+
+    // The `build` function
+    let (impl_generics, _, _) = new_struct.generics.split_for_impl();
+    let set_states: Vec<TokenStream> = new_struct
+        .fields
+        .iter()
+        .zip(&builder_fields)
+        .filter_map(|(field, builder_field)| match builder_field {
+            BuilderField::Required(_) => {
+                let ty = &field.ty;
+                Some(quote!((#ty,)))
+            }
+            BuilderField::Optional => None,
+        })
+        .collect();
+    let full_builder_type = builder_type(new_struct, &set_states);
+    let locals = new_struct.locals();
+    let bindings = new_struct.binding_order().into_iter().map(|idx| {
+        let field = &new_struct.fields[idx];
+        let (local, ty, slot) = (&locals[idx], &field.ty, slot(idx));
+        let value = match builder_fields[idx] {
+            BuilderField::Required(_) => quote!(builder.#slot.0),
+            BuilderField::Optional => {
+                let fallback = new_struct.fallback_value(field, &locals);
+                quote! {
+                    match builder.#slot {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => #fallback,
+                    }
+                }
+            }
+        };
+        quote!(let #local: #ty = #value;)
+    });
+    let instance = new_struct.instance(&locals);
+    let build_doc = format!(" Builds the [`{}`] instance.", struct_ident);
+
+    quote! {
+        #[doc = #builder_doc]
+        #struct_vis struct #builder_ident #struct_params #where_clause {
+            #(#slots_decl,)*
+            __impl_new_marker: ::core::marker::PhantomData<fn() -> #struct_ident #struct_ty_generics>,
+        }
+
+        #(#setters)*
+
+        impl #impl_generics #full_builder_type #where_clause {
+            #[doc = #build_doc]
+            #fn_vis fn build(self) -> #struct_ident #struct_ty_generics {
+                ::core::convert::From::from(self)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#full_builder_type> for #struct_ident #struct_ty_generics #where_clause {
+            fn from(builder: #full_builder_type) -> Self {
+                #(#bindings)*
+                #instance
+            }
+        }
+    }
+}
@@ -1,9 +1,23 @@
-use crate::{attrs::ImplNewAttr, utils};
-use darling::FromMeta;
-use proc_macro2::{Span, TokenStream};
+use crate::{
+    attrs::{ImplNewAttr, OptionalMode},
+    utils,
+};
+use darling::{util::SpannedValue, FromMeta};
+use proc_macro2::Span;
 use proc_macro_error::abort;
 use syn::spanned::Spanned;
 
+/// How the argument of a field is converted to the field type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Conversion {
+    /// The argument is the field type itself.
+    None,
+    /// The argument is `impl Into<T>`, converted with `Into::into`.
+    Into,
+    /// The argument is `impl TryInto<T>`, converted with `TryInto::try_into`.
+    TryInto,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct ImplNewField {
     /// The span of the field.
@@ -14,75 +28,97 @@ pub(crate) struct ImplNewField {
     pub(crate) ty: syn::Type,
     /// `#[impl_new(...)]` attribute.
     pub(crate) impl_new_attr: Option<ImplNewAttr>,
+    /// The lines of the field documentation (`///` comments), used in the generated function documentation.
+    pub(crate) docs: Vec<String>,
 }
 
 impl ImplNewField {
     pub(crate) fn parse(field: syn::Field) -> syn::Result<Self> {
         let span = field.span();
         let ident = field.ident.clone();
+        let docs = field
+            .attrs
+            .iter()
+            .filter_map(|attr| match attr.meta {
+                syn::Meta::NameValue(ref meta) if meta.path.is_ident("doc") => match meta.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(ref doc),
+                        ..
+                    }) => Some(doc.value()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
         let ty = field.ty.clone();
-        let impl_new_attrs = field
+        // All the `impl_new` attributes are parsed as one, so the options of all of them are merged
+        // and the duplicate options are reported on their spans.
+        let mut metas = Vec::new();
+        let mut has_attr = false;
+        for attr in field
             .attrs
             .iter()
-            .filter_map(|attr| {
-                if attr.path().is_ident("impl_new") {
-                    if let Ok(meta_list) = attr.meta.require_list() {
-                        Some(meta_list.tokens.clone())
-                    } else {
-                        abort!(
-                            attr,
-                            "Invalid `impl_new` attribute, expected #[impl_new(...)]."
-                        );
-                    }
-                } else {
-                    None
+            .filter(|attr| attr.path().is_ident("impl_new"))
+        {
+            match attr.meta.require_list() {
+                Ok(meta_list) => {
+                    metas.extend(darling::ast::NestedMeta::parse_meta_list(
+                        meta_list.tokens.clone(),
+                    )?);
+                    has_attr = true;
                 }
-            })
-            .map(|attr: TokenStream| {
-                match ImplNewAttr::from_list(&darling::ast::NestedMeta::parse_meta_list(attr)?) {
-                    Ok(opts) => Ok(opts),
-                    Err(err) => {
-                        utils::abort_error(err, ImplNewAttr::supported_options());
-                        unreachable!()
-                    }
+                Err(_) => abort!(
+                    attr,
+                    "Invalid `impl_new` attribute, expected #[impl_new(...)]."
+                ),
+            }
+        }
+        let impl_new_attr = if has_attr {
+            match ImplNewAttr::from_list(&metas) {
+                Ok(opts) => Some(opts),
+                Err(err) => {
+                    utils::abort_error(err, ImplNewAttr::supported_options());
+                    unreachable!()
                 }
-            })
-            .collect::<syn::Result<Vec<ImplNewAttr>>>()?;
-        let impl_new_attr = if let Some((first, rest)) = impl_new_attrs.split_first() {
-            let mut opts = first.clone();
-            opts.merge(rest);
-            Some(opts)
+            }
         } else {
             None
         };
-        utils::impl_new_checks(&ident, &ty, &impl_new_attr);
         Ok(Self {
             span,
             ident,
             ty,
             impl_new_attr,
+            docs,
         })
     }
 }
 
 impl ImplNewField {
     /// Returns the argument name of the field.
+    /// Will be None if the field uses the `default` or `value` option.
     pub fn arg_name(&self) -> Option<syn::Ident> {
-        if matches!(&self.impl_new_attr, Some(ImplNewAttr { default, value, .. }) if default.is_present() || value.is_some())
-        {
+        if self.has_fallback() {
             None
-        } else if let Some(name) = self
+        } else {
+            Some(
+                self.param_name()
+                    .expect("This will never happen, the unnamed fields are checked."),
+            )
+        }
+    }
+
+    /// Returns the name of the field when its used as an argument, even if the field uses the `default` or `value` option.
+    /// Will be None if the field is unnamed and doesn't have the `name` option.
+    pub fn param_name(&self) -> Option<syn::Ident> {
+        if let Some(name) = self
             .impl_new_attr
             .as_ref()
             .and_then(|attr| attr.name.as_ref())
         {
             Some(syn::Ident::new(name, name.span()))
         } else {
-            Some(
-                self.ident
-                    .clone()
-                    .expect("This will never happen, the unnamed fields are checked."),
-            )
+            self.ident.clone()
         }
     }
 
@@ -93,26 +129,185 @@ impl ImplNewField {
             .expect("Unnamed fields cannot be accessed.")
     }
 
-    /// Returns the field value.
-    pub fn value(&self) -> syn::Expr {
-        if matches!(self.impl_new_attr, Some(ImplNewAttr { default, .. }) if default.is_present()) {
-            let ty = &self.ty;
-            syn::parse_quote! { #ty::default() }
+    /// Returns true if the field uses the `default`, `value` or `optional` (without an argument) option.
+    pub fn has_fallback(&self) -> bool {
+        matches!(&self.impl_new_attr, Some(ImplNewAttr { default, value, .. }) if default.is_some() || value.is_some())
+            || self.optional() == Some(OptionalMode::Omit)
+    }
+
+    /// Returns true if the field uses the `with` option.
+    pub fn is_with(&self) -> bool {
+        matches!(self.impl_new_attr, Some(ImplNewAttr { with, .. }) if with.is_present())
+    }
+
+    /// Returns the `optional` option of the field.
+    pub fn optional(&self) -> Option<OptionalMode> {
+        self.impl_new_attr
+            .as_ref()
+            .and_then(|attr| attr.optional.as_deref())
+            .copied()
+    }
+
+    /// Returns true if the field type is `Option<T>`.
+    pub fn is_option(&self) -> bool {
+        utils::is_option_type(&self.ty)
+    }
+
+    /// Returns true if the argument of the field is converted with `Into::into`.
+    /// The `into` option of the field if set, otherwise the given struct default.
+    /// Always true for the `optional = "arg"` option, so it only changes the argument type when `into = false` is set,
+    /// the `impl Into<Option<T>>` argument is already the default otherwise.
+    pub fn is_into(&self, struct_default: bool) -> bool {
+        if self.optional() == Some(OptionalMode::Arg) {
+            return true;
+        }
+        self.impl_new_attr
+            .as_ref()
+            .and_then(|attr| attr.into.as_ref())
+            .map_or(struct_default, |into| *into.as_ref())
+    }
+
+    /// Sets the `optional` option of the field from the struct `optional` option.
+    /// Only for `Option<T>` fields that don't configure their argument or value.
+    pub fn inherit_optional(&mut self, optional: &SpannedValue<OptionalMode>) {
+        if !self.is_option() {
+            return;
+        }
+        let mut attr = self.impl_new_attr.clone().unwrap_or_default();
+        let is_configured = attr.optional.is_some()
+            || attr.default.is_some()
+            || attr.value.is_some()
+            || attr.into.is_some()
+            || attr.try_into.is_present()
+            || (attr.name.is_some() && **optional == OptionalMode::Omit);
+        if !is_configured {
+            attr.optional = Some(*optional);
+            self.impl_new_attr = Some(attr);
+        }
+    }
+
+    /// Returns true if the argument of the field is converted with `TryInto::try_into`.
+    pub fn is_try_into(&self) -> bool {
+        matches!(self.impl_new_attr, Some(ImplNewAttr { try_into, .. }) if try_into.is_present())
+    }
+
+    /// Returns the `validate` option of the field.
+    pub fn validator(&self) -> Option<&syn::Path> {
+        self.impl_new_attr
+            .as_ref()
+            .and_then(|attr| attr.validate.as_deref())
+    }
+
+    /// Returns how the argument of the field is converted to the field type.
+    pub fn conversion(&self, struct_into: bool) -> Conversion {
+        if self.is_try_into() {
+            Conversion::TryInto
+        } else if self.is_into(struct_into) {
+            Conversion::Into
+        } else {
+            Conversion::None
+        }
+    }
+
+    /// Returns the expression of the `value` or `default = <VALUE>` option, None if the field doesn't use them.
+    fn fallback_expr(&self) -> Option<&syn::Expr> {
+        let attr = self.impl_new_attr.as_ref()?;
+        match attr.default {
+            Some(ref default) => default.value.as_ref(),
+            None => attr.value.as_ref().map(|value| &value.value),
+        }
+    }
+
+    /// Returns the field value of the `default`, `value` or `optional` option as it's shown in the documentation.
+    /// None if the field doesn't have a fallback.
+    pub fn fallback_doc(&self) -> Option<String> {
+        if let Some(value) = self.fallback_expr() {
+            Some(utils::expr_to_doc(value))
+        } else if self.optional() == Some(OptionalMode::Omit) {
+            Some("None".to_owned())
+        } else if matches!(self.impl_new_attr, Some(ImplNewAttr { ref default, .. }) if default.is_some())
+        {
+            Some("Default::default()".to_owned())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the closure parameters of the `value` or `default = <VALUE>` option, empty if it's not a closure.
+    pub fn fallback_params(&self) -> Vec<&syn::Pat> {
+        match self.fallback_expr() {
+            Some(syn::Expr::Closure(closure)) => closure.inputs.iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the closure of the `value` or `default = <VALUE>` option if it's called instead of being inlined,
+    /// see [`is_inline_closure`].
+    pub fn fallback_closure(&self) -> Option<&syn::ExprClosure> {
+        match self.fallback_expr() {
+            Some(syn::Expr::Closure(closure)) if !is_inline_closure(closure) => Some(closure),
+            _ => None,
+        }
+    }
+
+    /// Returns the field value of the `default` or `value` option, None if the field doesn't use them.
+    /// The closure parameters are not bound, see [`NewStruct::fallback_value`](crate::new_struct::NewStruct::fallback_value).
+    pub fn fallback_value(&self) -> Option<syn::Expr> {
+        if let Some(value) = self.fallback_expr() {
+            Some(value_expr(value))
+        } else if self.optional() == Some(OptionalMode::Omit) {
+            Some(syn::parse_quote!(::core::option::Option::None))
         } else if let Some(ImplNewAttr {
-            value: Some(ref value),
+            default: Some(ref default),
             ..
         }) = self.impl_new_attr
         {
-            // get the body span
-            let sp = match value.as_ref() {
-                syn::Expr::Closure(ref expr) => expr.body.span(),
-                _ => unreachable!("The `value` option is checked to be a closure."),
-            };
-            let value = value.as_ref();
-            syn::parse_quote_spanned! { sp => (#value)() }
+            let ty = &self.ty;
+            Some(syn::parse_quote_spanned! { default.span() =>
+                <#ty as ::core::default::Default>::default()
+            })
         } else {
-            let arg_name = self.arg_name();
-            syn::parse_quote! { #arg_name.into() }
+            None
         }
     }
 }
+
+/// Returns the expression of the `value` and `default = <VALUE>` options.
+fn value_expr(value: &syn::Expr) -> syn::Expr {
+    match value {
+        // The closure body is used as the value, instead of calling the closure.
+        syn::Expr::Closure(ref expr) if is_inline_closure(expr) => {
+            let body = &expr.body;
+            syn::parse_quote_spanned! { body.span() => #body }
+        }
+        syn::Expr::Path(ref expr) if is_fn_path(&expr.path) => {
+            syn::parse_quote_spanned! { expr.span() => #expr() }
+        }
+        value => value.clone(),
+    }
+}
+
+/// Returns true if the closure body can be used as the value instead of calling the closure.
+/// A `return` or a `?` in the body would return from the generated function, so the closure is called.
+pub(crate) fn is_inline_closure(closure: &syn::ExprClosure) -> bool {
+    fn has_early_return(tokens: proc_macro2::TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => ident == "return",
+            proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '?',
+            proc_macro2::TokenTree::Group(group) => has_early_return(group.stream()),
+            proc_macro2::TokenTree::Literal(_) => false,
+        })
+    }
+    let body = &closure.body;
+    !has_early_return(quote::quote!(#body))
+}
+
+/// Returns true if the path is a function path, the last segment is a `snake_case` identifier.
+/// Otherwise it's a constant, a static or a unit struct/variant and it's used as is.
+fn is_fn_path(path: &syn::Path) -> bool {
+    path.segments.last().map_or(false, |segment| {
+        let ident = segment.ident.to_string();
+        ident.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+            && ident.chars().any(|c| c.is_ascii_lowercase())
+    })
+}
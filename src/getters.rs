@@ -0,0 +1,115 @@
+use darling::util::Flag;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::{attrs::ImplNewAttr, fields::ImplNewField, new_struct::NewStruct};
+
+/// How the getter of a field returns the field value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GetterKind {
+    /// `get`, returns a reference to the field.
+    Ref,
+    /// `get_copy`, returns a copy of the field.
+    Copy,
+    /// `get_clone`, returns a clone of the field.
+    Clone,
+}
+
+impl GetterKind {
+    /// Returns the option name of the getter kind.
+    pub(crate) const fn option(self) -> &'static str {
+        match self {
+            Self::Ref => "get",
+            Self::Copy => "get_copy",
+            Self::Clone => "get_clone",
+        }
+    }
+
+    /// Returns the getter kinds set by the given `get`, `get_copy` and `get_clone` options, with their spans.
+    pub(crate) fn from_flags(get: &Flag, get_copy: &Flag, get_clone: &Flag) -> Vec<(Self, Span)> {
+        [
+            (Self::Ref, get),
+            (Self::Copy, get_copy),
+            (Self::Clone, get_clone),
+        ]
+        .into_iter()
+        .filter(|(_, flag)| flag.is_present())
+        .map(|(kind, flag)| (kind, flag.span()))
+        .collect()
+    }
+}
+
+/// Returns the getter kind of the field, the field options override the struct options.
+pub(crate) fn getter_kind(new_struct: &NewStruct, field: &ImplNewField) -> Option<GetterKind> {
+    let field_kinds = match field.impl_new_attr {
+        Some(ImplNewAttr {
+            ref get,
+            ref get_copy,
+            ref get_clone,
+            ..
+        }) => GetterKind::from_flags(get, get_copy, get_clone),
+        None => Vec::new(),
+    };
+    let kinds = if field_kinds.is_empty() {
+        let attr = &new_struct.attr;
+        GetterKind::from_flags(&attr.get, &attr.get_copy, &attr.get_clone)
+    } else {
+        field_kinds
+    };
+    kinds.first().map(|(kind, _)| *kind)
+}
+
+/// Returns true if the field has a `get_mut` getter, from the field or the struct option.
+pub(crate) fn has_getter_mut(new_struct: &NewStruct, field: &ImplNewField) -> bool {
+    new_struct.attr.get_mut.is_present()
+        || matches!(field.impl_new_attr, Some(ImplNewAttr { get_mut, .. }) if get_mut.is_present())
+}
+
+/// Generates the getters of the struct fields.
+pub(crate) fn getters(new_struct: &NewStruct) -> TokenStream {
+    let vis = new_struct.fn_vis();
+    let getters = new_struct
+        .fields
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, field)| {
+            let name = field.param_name()?;
+            let ty = &field.ty;
+            let member = if new_struct.is_tuple_struct {
+                let index = syn::Index::from(idx);
+                quote!(#index)
+            } else {
+                let ident = field.field_name();
+                quote!(#ident)
+            };
+            let getter = getter_kind(new_struct, field).map(|kind| {
+                let doc = format!(" Returns the `{}` field.", name);
+                let (return_type, value) = match kind {
+                    GetterKind::Ref => (quote!(&#ty), quote!(&self.#member)),
+                    GetterKind::Copy => (quote!(#ty), quote!(self.#member)),
+                    GetterKind::Clone => (
+                        quote!(#ty),
+                        quote!(::core::clone::Clone::clone(&self.#member)),
+                    ),
+                };
+                quote! {
+                    #[doc = #doc]
+                    #vis fn #name(&self) -> #return_type {
+                        #value
+                    }
+                }
+            });
+            let getter_mut = has_getter_mut(new_struct, field).then(|| {
+                let doc = format!(" Returns a mutable reference to the `{}` field.", name);
+                let fn_name = format_ident!("{}_mut", name);
+                quote! {
+                    #[doc = #doc]
+                    #vis fn #fn_name(&mut self) -> &mut #ty {
+                        &mut self.#member
+                    }
+                }
+            });
+            Some(quote!(#getter #getter_mut))
+        });
+    quote!(#(#getters)*)
+}
@@ -3,20 +3,59 @@
 
 extern crate proc_macro;
 
-use new_struct::NewStruct;
+use attrs::{ImplNewContainerAttr, ImplNewVariantAttr};
+use darling::FromVariant;
+use new_struct::{Constructor, NewStruct, NewVariant};
 use proc_macro::TokenStream;
 use quote::quote;
 
 mod attrs;
+mod builder;
 mod fields;
+mod getters;
 mod new_struct;
+mod trait_impls;
 mod utils;
 
-/// Derive macro that implements a new function for a struct.
+/// Derive macro that implements a new function for a struct, or a function for each variant of an enum.
 /// ## Attributes
 /// - `#[impl_new(name = "name")]`: Use this attribute to change the name of the argument in the generated `new` function.
 /// - `#[impl_new(default)]`: Use this attribute to remove the field from the generated `new` function and use the default value instead.
-/// - `#[impl_new(value = || <VALUE>)]`: Use this attribute to remove the field from the generated `new` function and use the given value instead.
+/// - `#[impl_new(default = <VALUE>)]`: Use this attribute to remove the field from the generated `new` function and use the given value instead.
+/// - `#[impl_new(value = <VALUE>)]`: Use this attribute to remove the field from the generated `new` function and use the given value instead. The value can be an expression, a function path (called with no arguments) or a closure, its parameters are the other arguments values by reference.
+/// - `#[impl_new(into = false)]`: Use this attribute to take the argument as the field type itself instead of `impl Into<T>`.
+/// - `#[impl_new(try_into)]`: Use this attribute to take the argument as `impl TryInto<T>`, the generated function will return a `Result`.
+/// - `#[impl_new(validate = path::to::validator)]`: Use this attribute to validate the field value with the given function, the generated function will return a `Result`.
+/// - `#[impl_new(with)]`: Use this attribute on the fields that use the `default`, `value` or `optional` option to generate a `with_<field>` function that sets the field.
+/// - `#[impl_new(get)]`, `#[impl_new(get_copy)]`, `#[impl_new(get_clone)]`: Use these attributes to generate a getter named by the argument name of the field, that returns a reference, a copy or a clone of the field.
+/// - `#[impl_new(get_mut)]`: Use this attribute to generate a `<name>_mut` getter that returns a mutable reference to the field.
+/// - `#[impl_new(optional)]`: Use this attribute on `Option<T>` fields to remove them from the generated `new` function and set them to `None`, or with `optional = "arg"` to take them as `impl Into<Option<T>>`.
+///
+/// ## Struct Attributes
+/// - `#[impl_new(vis = "pub(crate)")]`: Use this attribute to change the visibility of the generated `new` function, by default it has the visibility of the struct.
+/// - `#[impl_new(fn_name = "function_name")]`: Use this attribute to change the name of the generated function, by default it's `new`.
+/// - `#[impl_new(constructor(name = "function_name", args(field, ...)))]`: Use this attribute to generate multiple functions, each one takes the given fields as arguments (all the fields if `args` is not set) and the rest of the fields must use the `default` or `value` option.
+/// - `#[impl_new(into = false)]`: Use this attribute to take all the arguments as the fields types instead of `impl Into<T>`, fields can override it.
+/// - `#[impl_new(const)]`: Use this attribute to generate a `const` function, the arguments are taken as the fields types.
+/// - `#[impl_new(try_new)]`: Use this attribute to make the generated function return a `Result`, by default it's named `try_new`.
+/// - `#[impl_new(error = "ErrorType")]`: Use this attribute to set the error type of the fallible function, by default a `<Struct>Error` enum is generated.
+/// - `#[impl_new(validate = Self::validator)]`: Use this attribute to validate the created instance with the given function, the generated function will return a `Result`.
+/// - `#[impl_new(validate_all)]`: Use this attribute to return all the fields validation errors instead of the first one.
+/// - `#[impl_new(optional)]`: Use this attribute to apply the field `optional` option to all the `Option<T>` fields.
+/// - `#[impl_new(with)]`: Use this attribute to generate a `with_<field>` function for all the fields that use the `default`, `value` or `optional` option.
+/// - `#[impl_new(get)]`, `#[impl_new(get_mut)]`, `#[impl_new(get_copy)]`, `#[impl_new(get_clone)]`: Use these attributes to generate the getters for all the fields, the fields options override them.
+/// - `#[impl_new(builder)]`: Use this attribute to also generate a `<Struct>Builder` with a setter for each field, and a `build` function that is available once all the required fields are set.
+/// - `#[impl_new(from_tuple)]`: Use this attribute to also implement `From<(A, B, ...)>` for the struct, with the arguments of the generated function.
+/// - `#[impl_new(from_arg)]`: Use this attribute to also implement `From<T>` for the struct with a single argument, where `T: Into<A>` (or `A` itself if it's not converted or uses a generic of the struct).
+/// - `#[impl_new(impl_default)]`: Use this attribute to also implement `Default` for the struct, all the fields must use the `default` or `value` option.
+/// - `#[impl_new(doc = "...")]`: Use this attribute to replace the documentation of the generated `new` function.
+/// - `#[impl_new(doc_append = "...")]`: Use this attribute to append a paragraph to the documentation of the generated `new` function.
+/// - `#[impl_new(fn_attrs(attr, ...))]`: Use this attribute to add the given attributes to the generated constructors, e.g. `fn_attrs(inline, deprecated(note = "..."))`.
+/// - `#[impl_new(must_use = false)]`: Use this attribute to remove the default `#[must_use]` of the generated infallible constructors.
+///
+/// ## Variant Attributes
+/// - `#[impl_new(fn_name = "function_name")]`: Use this attribute to change the name of the variant function, by default it's `new_<variant>`.
+/// - `#[impl_new(skip)]`: Use this attribute to skip the variant, no function will be generated for it.
 ///
 //// ## Example
 /// ### For Named Fields
@@ -94,69 +133,626 @@ pub fn new_derive(input: TokenStream) -> TokenStream {
     gen.into()
 }
 
-/// Implements the `new` function for the given struct.
-fn new_function(new_struct: NewStruct) -> proc_macro2::TokenStream {
-    let new_function_doc = format!(" Creates a new [`{}`] instance.", new_struct.ident);
-    let arg_names: Vec<proc_macro2::Ident> = new_struct
+/// Implements the given constructor function for the given struct or enum variant.
+fn new_function(new_struct: &NewStruct, constructor: &Constructor) -> proc_macro2::TokenStream {
+    let mut new_function_doc = match new_struct.attr.doc {
+        Some(ref doc) => doc.as_str().to_owned(),
+        None => new_struct.default_doc(constructor),
+    };
+    if let Some(ref doc_append) = new_struct.attr.doc_append {
+        new_function_doc.push_str("\n\n");
+        new_function_doc.push_str(doc_append);
+    }
+    let arg_names = constructor.args.iter().map(|arg| &arg.name);
+    let types = constructor.args.iter().map(|arg| &arg.ty);
+    let vis = new_struct.fn_vis();
+    let fn_name = &constructor.ident;
+    let fn_attrs = new_struct.fn_attrs();
+    let constness = new_struct.is_const().then(|| quote!(const));
+    let locals = new_struct.locals();
+    let bindings = new_struct.binding_order().into_iter().map(|idx| {
+        let field = &new_struct.fields[idx];
+        let (local, ty) = (&locals[idx], &field.ty);
+        let value = match constructor.arg(idx) {
+            Some(arg) => {
+                let value = &arg.value;
+                quote!(#value)
+            }
+            None => new_struct.fallback_value(field, &locals),
+        };
+        quote!(let #local: #ty = #value;)
+    });
+    let bindings = quote!(#(#bindings)*);
+
+    if new_struct.is_fallible() {
+        let result_error_type = new_struct.result_error_type();
+        let validations = validations(new_struct, &locals);
+        quote! {
+            #[doc = #new_function_doc]
+            #fn_attrs
+            #vis fn #fn_name(#(#arg_names: #types),*) -> ::core::result::Result<Self, #result_error_type> {
+                #bindings
+                #validations
+                ::core::result::Result::Ok(__impl_new_self)
+            }
+        }
+    } else {
+        let instance = new_struct.instance(&locals);
+        quote! {
+            #[doc = #new_function_doc]
+            #fn_attrs
+            #vis #constness fn #fn_name(#(#arg_names: #types),*) -> Self {
+                #bindings
+                #instance
+            }
+        }
+    }
+}
+
+/// Generates the `with_<field>` functions, that set the fields that are not arguments.
+fn with_functions(new_struct: &NewStruct) -> proc_macro2::TokenStream {
+    let vis = new_struct.fn_vis();
+    let functions = new_struct.with_fields().into_iter().map(|field| {
+        let field_name = field.field_name();
+        let fn_name = quote::format_ident!("with_{}", field_name);
+        let arg_type = new_struct.arg_type(field);
+        let value = new_struct.arg_value(field);
+        let doc = format!(" Sets the `{}` field.", field_name);
+        quote! {
+            #[doc = #doc]
+            #vis fn #fn_name(mut self, #field_name: #arg_type) -> Self {
+                self.#field_name = #value;
+                self
+            }
+        }
+    });
+    quote!(#(#functions)*)
+}
+
+/// Generates the validation of the fields values, then the instance (`__impl_new_self`) creation and its validation.
+fn validations(new_struct: &NewStruct, locals: &[proc_macro2::Ident]) -> proc_macro2::TokenStream {
+    let field_validations = new_struct
         .fields
         .iter()
-        .filter_map(|field| field.arg_name())
+        .zip(locals)
+        .filter_map(|(field, local)| {
+            let validator = field.validator()?;
+            let error_variant = utils::error_variant_ident(
+                &field
+                    .param_name()
+                    .expect("The validated fields are checked to have a name."),
+            );
+            if new_struct.is_validate_all() {
+                let map_err = new_struct.map_err(&error_variant);
+                Some(quote! {
+                    if let ::core::result::Result::Err(error) = #validator(&#local) {
+                        __impl_new_errors.push((#map_err)(error));
+                    }
+                })
+            } else {
+                let validation =
+                    new_struct.try_expr(syn::parse_quote!(#validator(&#local)), &error_variant);
+                Some(quote!(#validation;))
+            }
+        })
+        .collect::<Vec<_>>();
+    let instance = new_struct.instance(locals);
+    let self_validation = new_struct.attr.validate.as_ref().map(|validator| {
+        let validator = validator.as_ref();
+        let validation = new_struct.try_expr(
+            syn::parse_quote!(#validator(&__impl_new_self)),
+            &utils::container_error_variant_ident(),
+        );
+        quote!(#validation;)
+    });
+
+    if new_struct.is_validate_all() && !field_validations.is_empty() {
+        let error_type = new_struct.error_type();
+        quote! {
+            let mut __impl_new_errors: ::std::vec::Vec<#error_type> = ::std::vec::Vec::new();
+            #(#field_validations)*
+            if !__impl_new_errors.is_empty() {
+                return ::core::result::Result::Err(__impl_new_errors);
+            }
+            let __impl_new_self = #instance;
+            #self_validation
+        }
+    } else {
+        quote! {
+            #(#field_validations)*
+            let __impl_new_self = #instance;
+            #self_validation
+        }
+    }
+}
+
+/// Parses the given fields.
+fn parse_fields(fields: &syn::Fields) -> syn::Result<Vec<fields::ImplNewField>> {
+    fields
+        .clone()
+        .into_iter()
+        .map(fields::ImplNewField::parse)
+        .collect()
+}
+
+/// Parses the struct, or the enum variants, to generate the functions for.
+fn parse_new_structs(
+    ast: &syn::DeriveInput,
+    container_attr: ImplNewContainerAttr,
+) -> syn::Result<Vec<NewStruct>> {
+    match ast.data {
+        syn::Data::Struct(ref data) => Ok(vec![NewStruct::new(
+            ast,
+            None,
+            parse_fields(&data.fields)?,
+            container_attr,
+        )]),
+        syn::Data::Enum(ref data) => {
+            let mut new_structs = Vec::new();
+            for variant in &data.variants {
+                let variant_attr = match ImplNewVariantAttr::from_variant(variant) {
+                    Ok(attr) => attr,
+                    Err(err) => {
+                        utils::abort_error(err, ImplNewVariantAttr::supported_options());
+                        unreachable!()
+                    }
+                };
+                if variant_attr.skip.is_present() {
+                    continue;
+                }
+                new_structs.push(NewStruct::new(
+                    ast,
+                    Some(NewVariant::new(variant, variant_attr)),
+                    parse_fields(&variant.fields)?,
+                    container_attr.clone(),
+                ));
+            }
+            Ok(new_structs)
+        }
+        _ => unreachable!("The `impl_new::New` macro can only be used on structs and enums."),
+    }
+}
+
+/// Generates the error type of the fallible functions, if they don't have the `error` option.
+fn error_type(
+    ast: &syn::DeriveInput,
+    new_structs: &[NewStruct],
+) -> Option<proc_macro2::TokenStream> {
+    let fallible_structs: Vec<&NewStruct> = new_structs
+        .iter()
+        .filter(|new_struct| new_struct.is_fallible() && new_struct.attr.error.is_none())
         .collect();
-    let types: Vec<syn::Type> = new_struct
-        .fields
+    if fallible_structs.is_empty() {
+        return None;
+    }
+    let mut arg_names: Vec<proc_macro2::Ident> = Vec::new();
+    for field in fallible_structs
         .iter()
-        .map(|field| field.ty.clone())
+        .flat_map(|new_struct| new_struct.fields.iter())
+        .filter(|field| field.is_try_into() || field.validator().is_some())
+    {
+        let arg_name = field
+            .param_name()
+            .expect("The `try_into` and `validate` fields are checked to have a name.");
+        if !arg_names.contains(&arg_name) {
+            arg_names.push(arg_name);
+        }
+    }
+    let vis = &ast.vis;
+    let error_ident = utils::error_ident(&ast.ident);
+    let error_doc = format!(" The error of the fallible [`{}`] functions.", ast.ident);
+    let mut variants: Vec<proc_macro2::Ident> =
+        arg_names.iter().map(utils::error_variant_ident).collect();
+    let mut variants_doc: Vec<String> = arg_names
+        .iter()
+        .map(|arg_name| format!(" The `{}` argument is invalid.", arg_name))
         .collect();
-    let values: Vec<syn::Expr> = new_struct
-        .fields
+    let mut display_msgs: Vec<String> = arg_names
         .iter()
-        .map(|field| field.value())
+        .map(|arg_name| format!("invalid `{}` argument: {{}}", arg_name))
         .collect();
+    if fallible_structs
+        .iter()
+        .any(|new_struct| new_struct.attr.validate.is_some())
+    {
+        variants.push(utils::container_error_variant_ident());
+        variants_doc.push(format!(" The [`{}`] validation failed.", ast.ident));
+        display_msgs.push(format!("invalid `{}`: {{}}", ast.ident));
+    }
+    let error_source = quote!(
+        ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync>
+    );
 
-    if new_struct.is_tuple_struct {
-        quote! {
-            #[doc = #new_function_doc]
-            pub fn new(#(#arg_names: impl Into<#types>),*) -> Self {
-                Self(#(#values),*)
+    Some(quote! {
+        #[doc = #error_doc]
+        #[derive(Debug)]
+        #vis enum #error_ident {
+            #(
+                #[doc = #variants_doc]
+                #variants(#error_source),
+            )*
+        }
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match *self {
+                    #(Self::#variants(ref error) => ::core::write!(f, #display_msgs, error),)*
+                }
             }
         }
-    } else {
-        let names = new_struct.fields.iter().map(|field| field.field_name());
-        quote!(
-            #[doc = #new_function_doc]
-            pub fn new(#(#arg_names: impl Into<#types>),*) -> Self {
-                Self { #(#names: #values),* }
+
+        impl ::std::error::Error for #error_ident {
+            fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+                match *self {
+                    #(Self::#variants(ref error) => ::core::option::Option::Some(&**error),)*
+                }
             }
-        )
-    }
+        }
+    })
 }
 
 fn impl_new(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     utils::derive_input_checks(ast);
-    let struct_fields = match ast.data {
-        syn::Data::Struct(ref data) => data
-            .fields
-            .clone()
-            .into_iter()
-            .map(fields::ImplNewField::parse)
-            .collect::<syn::Result<Vec<fields::ImplNewField>>>(),
-        _ => unreachable!("The `impl_new::New` macro can only be used on structs."),
+    let container_attr = match ImplNewContainerAttr::parse(ast) {
+        Ok(attr) => attr,
+        Err(err) => {
+            utils::abort_error(err, ImplNewContainerAttr::supported_options());
+            unreachable!()
+        }
     };
+    utils::container_checks(ast, &container_attr);
 
-    match struct_fields {
-        Ok(fields) => {
-            utils::new_macro_checks(&fields);
-            let new_struct = NewStruct::new(ast, fields);
-            let new_function = new_function(new_struct);
+    match parse_new_structs(ast, container_attr) {
+        Ok(new_structs) => {
+            for new_struct in &new_structs {
+                utils::new_macro_checks(&new_struct.fields);
+                utils::constructors_checks(new_struct);
+                utils::fallback_checks(new_struct);
+                utils::const_checks(new_struct);
+            }
+            utils::variants_checks(&new_structs);
+            utils::validate_checks(&new_structs);
+            utils::builder_checks(ast, &new_structs);
+            utils::from_checks(ast, &new_structs);
+            utils::impl_default_checks(ast, &new_structs);
+            utils::with_checks(ast, &new_structs);
+            utils::getters_checks(ast, &new_structs);
+            utils::fallible_checks(&new_structs);
             let struct_name = &ast.ident;
+            let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+            let new_functions = new_structs.iter().flat_map(|new_struct| {
+                new_struct
+                    .constructors()
+                    .into_iter()
+                    .map(move |constructor| new_function(new_struct, &constructor))
+            });
+            let with_functions = new_structs.iter().map(with_functions);
+            let getters = new_structs.iter().map(getters::getters);
+            let error_type = error_type(ast, &new_structs);
+            let (builder_function, builder) = match new_structs.first() {
+                Some(new_struct) if new_struct.attr.builder.is_present() => (
+                    Some(builder::builder_function(new_struct)),
+                    Some(builder::builder(new_struct)),
+                ),
+                _ => (None, None),
+            };
+            let trait_impls = new_structs.first().map(|new_struct| {
+                let from_tuple = new_struct
+                    .attr
+                    .from_tuple
+                    .is_present()
+                    .then(|| trait_impls::from_tuple(new_struct));
+                let from_arg = new_struct
+                    .attr
+                    .from_arg
+                    .is_present()
+                    .then(|| trait_impls::from_arg(new_struct));
+                let default_impl = new_struct
+                    .attr
+                    .impl_default
+                    .is_present()
+                    .then(|| trait_impls::default_impl(new_struct));
+                quote!(#from_tuple #from_arg #default_impl)
+            });
             quote!(
-                #[allow(clippy::redundant_closure)]
-                #[allow(clippy::redundant_closure_call)]
-                impl #struct_name {
-                    #new_function
+                impl #impl_generics #struct_name #ty_generics #where_clause {
+                    #(#new_functions)*
+                    #builder_function
+                    #(#with_functions)*
+                    #(#getters)*
                 }
+
+                #error_type
+                #builder
+                #trait_impls
             )
         }
         Err(err) => err.to_compile_error(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    fn function_doc(ast: syn::DeriveInput) -> String {
+        let container_attr = ImplNewContainerAttr::parse(&ast).unwrap();
+        let new_structs = parse_new_structs(&ast, container_attr).unwrap();
+        let constructor = &new_structs[0].constructors()[0];
+        new_structs[0].default_doc(constructor)
+    }
+
+    #[test]
+    fn default_doc_arguments_and_defaults() {
+        let doc = function_doc(syn::parse_quote! {
+            struct User {
+                /// The name of the user.
+                ///
+                /// It's shown in the profile.
+                name: String,
+                age: u8,
+                #[impl_new(value = |name| !name.is_empty() && name.len() > 2)]
+                is_valid: bool,
+                #[impl_new(default = "hello (world) [x].y".to_owned())]
+                greeting: String,
+                #[impl_new(default)]
+                is_admin: bool,
+                #[impl_new(optional)]
+                email: Option<String>,
+                #[impl_new(default = Vec::<u8>::with_capacity(2 * 4))]
+                ids: Vec<u8>,
+            }
+        });
+        assert_eq!(
+            doc,
+            " Creates a new [`User`] instance.\n\
+             \n # Arguments\n\
+             \n * `name` - The name of the user.\n   \n   It's shown in the profile.\
+             \n * `age`\n\
+             \n # Defaults\n\
+             \n The fields that are not arguments are set to:\n\
+             \n * `is_valid`: `|name| !name.is_empty() && name.len() > 2`\
+             \n * `greeting`: `\"hello (world) [x].y\".to_owned()`\
+             \n * `is_admin`: `Default::default()`\
+             \n * `email`: `None`\
+             \n * `ids`: `Vec::<u8>::with_capacity(2 * 4)`"
+        );
+    }
+
+    #[test]
+    fn default_doc_non_exhaustive() {
+        let doc = function_doc(syn::parse_quote! {
+            #[non_exhaustive]
+            struct Config {
+                port: u16,
+            }
+        });
+        assert_eq!(
+            doc,
+            " Creates a new [`Config`] instance.\n\
+             \n [`Config`] is `#[non_exhaustive]`, so it can't be created with a struct expression \
+             outside of its crate, this function is the way to create it.\n\
+             \n # Arguments\n\
+             \n * `port`"
+        );
+
+        let doc = function_doc(syn::parse_quote! {
+            enum Event {
+                #[non_exhaustive]
+                Click { x: u16 },
+            }
+        });
+        assert_eq!(
+            doc,
+            " Creates a new [`Event::Click`] instance.\n\
+             \n [`Event::Click`] is `#[non_exhaustive]`, so it can't be created with a struct expression \
+             outside of its crate, this function is the way to create it.\n\
+             \n # Arguments\n\
+             \n * `x`"
+        );
+
+        let doc = function_doc(syn::parse_quote! {
+            #[non_exhaustive]
+            enum Event {
+                Click { x: u16 },
+            }
+        });
+        assert_eq!(
+            doc,
+            " Creates a new [`Event::Click`] instance.\n\n # Arguments\n\n * `x`"
+        );
+    }
+
+    fn function(ast: syn::DeriveInput) -> syn::ImplItemFn {
+        let container_attr = ImplNewContainerAttr::parse(&ast).unwrap();
+        let new_structs = parse_new_structs(&ast, container_attr).unwrap();
+        let constructor = &new_structs[0].constructors()[0];
+        syn::parse2(new_function(&new_structs[0], constructor)).unwrap()
+    }
+
+    fn function_docs(ast: syn::DeriveInput) -> Vec<String> {
+        function(ast)
+            .attrs
+            .iter()
+            .filter_map(|attr| match attr.meta {
+                syn::Meta::NameValue(ref meta) if meta.path.is_ident("doc") => match meta.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(ref doc),
+                        ..
+                    }) => Some(doc.value()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn doc_and_doc_append() {
+        let docs = function_docs(syn::parse_quote! {
+            #[impl_new(doc = " Creates a user.")]
+            struct User {
+                name: String,
+            }
+        });
+        assert_eq!(docs, [" Creates a user."]);
+
+        let docs = function_docs(syn::parse_quote! {
+            #[impl_new(doc_append = " # Panics\n\n Never.")]
+            struct User {
+                name: String,
+            }
+        });
+        assert_eq!(
+            docs,
+            [" Creates a new [`User`] instance.\n\n # Arguments\n\n * `name`\n\n # Panics\n\n Never."]
+        );
+
+        let docs = function_docs(syn::parse_quote! {
+            #[impl_new(doc = " Creates a user.", doc_append = " See [`User`].")]
+            struct User {
+                name: String,
+            }
+        });
+        assert_eq!(docs, [" Creates a user.\n\n See [`User`]."]);
+    }
+
+    fn function_attrs(ast: syn::DeriveInput) -> Vec<String> {
+        function(ast)
+            .attrs
+            .iter()
+            .filter(|attr| !matches!(attr.meta, syn::Meta::NameValue(ref meta) if meta.path.is_ident("doc")))
+            .map(|attr| attr.to_token_stream().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn must_use_constructors() {
+        let attrs = function_attrs(syn::parse_quote! {
+            struct Test {
+                name: String,
+            }
+        });
+        assert_eq!(attrs, ["# [must_use]"]);
+
+        let attrs = function_attrs(syn::parse_quote! {
+            #[impl_new(fn_attrs(inline, must_use = "unused"))]
+            struct Test {
+                name: String,
+            }
+        });
+        assert_eq!(attrs, ["# [inline]", "# [must_use = \"unused\"]"]);
+
+        let attrs = function_attrs(syn::parse_quote! {
+            #[impl_new(must_use = false, fn_attrs(inline))]
+            struct Test {
+                name: String,
+            }
+        });
+        assert_eq!(attrs, ["# [inline]"]);
+
+        let attrs = function_attrs(syn::parse_quote! {
+            #[impl_new(try_new)]
+            struct Test {
+                name: String,
+            }
+        });
+        assert!(attrs.is_empty());
+    }
+
+    #[test]
+    fn forwarded_fn_attrs() {
+        let attrs = function_attrs(syn::parse_quote! {
+            #[impl_new(fn_attrs(inline, track_caller, doc(hidden)))]
+            struct Test {
+                name: String,
+            }
+        });
+        assert_eq!(
+            attrs,
+            [
+                "# [must_use]",
+                "# [inline]",
+                "# [track_caller]",
+                "# [doc (hidden)]"
+            ]
+        );
+
+        let attrs = function_attrs(syn::parse_quote! {
+            #[impl_new(fn_attrs(deprecated(note = "Use `Test::builder` instead.")))]
+            struct Test {
+                name: String,
+            }
+        });
+        assert_eq!(
+            attrs,
+            [
+                "# [must_use]",
+                "# [deprecated (note = \"Use `Test::builder` instead.\")]"
+            ]
+        );
+    }
+
+    #[test]
+    fn default_doc_without_arguments() {
+        let doc = function_doc(syn::parse_quote! {
+            struct Marker;
+        });
+        assert_eq!(doc, " Creates a new [`Marker`] instance.");
+    }
+
+    #[test]
+    fn expr_to_doc() {
+        let cases: Vec<(syn::Expr, &str)> = vec![
+            (syn::parse_quote!(vec![1, 2]), "vec![1, 2]"),
+            (syn::parse_quote!(-1), "-1"),
+            (syn::parse_quote!(&mut a[0]), "&mut a[0]"),
+            (syn::parse_quote!(a || !b), "a || !b"),
+            (syn::parse_quote!(x as u64 >= 18), "x as u64 >= 18"),
+            (
+                syn::parse_quote!(<T as Default>::default()),
+                "<T as Default>::default()",
+            ),
+            (syn::parse_quote!(|| { 1 }), "|| { 1 }"),
+            (
+                syn::parse_quote!(move |a, b: &u8| a + *b),
+                "move |a, b: &u8| a + *b",
+            ),
+            (
+                syn::parse_quote!(items.iter().map(|item| item?.len()).sum::<usize>()),
+                "items.iter().map(|item| item?.len()).sum::<usize>()",
+            ),
+            (syn::parse_quote!(0..=10), "0..=10"),
+            (syn::parse_quote!(x as Vec<u8>), "x as Vec<u8>"),
+            (
+                syn::parse_quote!(x as Option<Vec<u8>> == None),
+                "x as Option<Vec<u8>> == None",
+            ),
+            (
+                syn::parse_quote!(x as u8 > y && a < b),
+                "x as u8 > y && a < b",
+            ),
+            (syn::parse_quote!(x as &u8), "x as &u8"),
+            (
+                syn::parse_quote!(|a: Vec<u8>, b| -> Result<u8, E> { Ok(a[0] + b) }),
+                "|a: Vec<u8>, b| -> Result<u8, E> { Ok(a[0] + b) }",
+            ),
+            (
+                syn::parse_quote!({
+                    let v: HashMap<(u8, u8), Vec<u8>> = m;
+                    v.len() < n
+                }),
+                "{ let v: HashMap<(u8, u8), Vec<u8>> = m; v.len() < n }",
+            ),
+            (syn::parse_quote!(S { a: 1 }), "S { a: 1 }"),
+            (
+                syn::parse_quote!(::std::time::Instant::now()),
+                "::std::time::Instant::now()",
+            ),
+        ];
+        for (expr, expected) in cases {
+            assert_eq!(utils::expr_to_doc(&expr), expected);
+        }
+    }
+}
@@ -1,25 +1,531 @@
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
 use syn::spanned::Spanned;
 
-use crate::{fields::ImplNewField, utils};
+use crate::{
+    attrs::{ImplNewContainerAttr, ImplNewVariantAttr},
+    fields::{Conversion, ImplNewField},
+    utils,
+};
 
+/// A struct, or an enum variant, to generate the functions for.
 pub(crate) struct NewStruct {
     pub(crate) ident: syn::Ident,
+    /// The enum variant, None if its a struct.
+    pub(crate) variant: Option<NewVariant>,
+    /// The visibility of the struct.
+    pub(crate) vis: syn::Visibility,
+    pub(crate) generics: syn::Generics,
     pub(crate) is_tuple_struct: bool,
+    /// Whether the struct or the variant has no fields, `struct Marker;`.
+    pub(crate) is_unit: bool,
+    /// Whether the struct or the variant has the `#[non_exhaustive]` attribute.
+    pub(crate) is_non_exhaustive: bool,
     pub(crate) fields: Vec<ImplNewField>,
+    /// `#[impl_new(...)]` attribute of the struct itself.
+    pub(crate) attr: ImplNewContainerAttr,
     pub(crate) span: Span,
 }
 
 impl NewStruct {
-    pub(crate) fn new(ast: &syn::DeriveInput, fields: Vec<ImplNewField>) -> Self {
+    pub(crate) fn new(
+        ast: &syn::DeriveInput,
+        variant: Option<NewVariant>,
+        mut fields: Vec<ImplNewField>,
+        attr: ImplNewContainerAttr,
+    ) -> Self {
+        if let Some(ref optional) = attr.optional {
+            for field in fields.iter_mut() {
+                field.inherit_optional(optional);
+            }
+        }
+        // The fields are checked after inheriting the struct options, an unnamed field can be
+        // configured by the struct `optional` option.
+        for field in &fields {
+            utils::impl_new_checks(&field.ident, &field.ty, &field.impl_new_attr);
+        }
         let ident = ast.ident.clone();
-        let is_tuple_struct = utils::is_tuple_struct(ast);
-        let span = ast.span();
+        let vis = ast.vis.clone();
+        let generics = ast.generics.clone();
+        let is_tuple_struct = match variant {
+            Some(ref variant) => variant.is_tuple,
+            None => utils::is_tuple_struct(ast),
+        };
+        let (is_unit, is_non_exhaustive) = match variant {
+            Some(ref variant) => (variant.is_unit, variant.is_non_exhaustive),
+            None => (
+                utils::is_unit_struct(ast),
+                utils::is_non_exhaustive(&ast.attrs),
+            ),
+        };
+        let span = match variant {
+            Some(ref variant) => variant.span,
+            None => ast.span(),
+        };
         Self {
             ident,
+            variant,
+            vis,
+            generics,
             is_tuple_struct,
+            is_unit,
+            is_non_exhaustive,
             fields,
+            attr,
             span,
         }
     }
+
+    /// Returns the visibility of the generated function.
+    /// The `vis` option of the struct attribute if set, otherwise the struct visibility.
+    pub(crate) fn fn_vis(&self) -> &syn::Visibility {
+        self.attr.visibility.as_ref().unwrap_or(&self.vis)
+    }
+
+    /// Returns the attributes of the generated constructors, the `fn_attrs` option of the struct attribute.
+    /// The infallible constructors are `#[must_use]` unless the `fn_attrs` option already has it or `must_use = false` is set,
+    /// the fallible ones return a `Result` that is already `#[must_use]`.
+    pub(crate) fn fn_attrs(&self) -> TokenStream {
+        let metas = self
+            .attr
+            .fn_attrs
+            .as_ref()
+            .map_or(&[][..], |fn_attrs| fn_attrs.0.as_slice());
+        let must_use = (!self.is_fallible()
+            && self
+                .attr
+                .must_use
+                .as_deref()
+                .map_or(true, |must_use| *must_use)
+            && !metas.iter().any(|meta| meta.path().is_ident("must_use")))
+        .then(|| quote!(#[must_use]));
+        quote!(#must_use #(#[#metas])*)
+    }
+
+    /// Returns the name of the generated function.
+    /// The `fn_name` option of the struct attribute if set, otherwise `new` (`try_new` if its fallible).
+    /// For enum variants the `fn_name` option of the variant attribute if set, otherwise `new_<variant>` (`try_new_<variant>` if its fallible).
+    pub(crate) fn fn_name(&self) -> Ident {
+        let prefix = if self.is_fallible() { "try_new" } else { "new" };
+        if let Some(ref variant) = self.variant {
+            return match variant.attr.fn_name {
+                Some(ref name) => Ident::new(name, name.span()),
+                None => Ident::new(
+                    &format!(
+                        "{}_{}",
+                        prefix,
+                        utils::to_snake_case(&variant.ident.to_string())
+                    ),
+                    variant.ident.span(),
+                ),
+            };
+        }
+        match self.attr.fn_name {
+            Some(ref name) => Ident::new(name, name.span()),
+            None => Ident::new(prefix, Span::call_site()),
+        }
+    }
+
+    /// Returns the path of the struct or the enum variant, that is used to create it.
+    pub(crate) fn self_path(&self) -> syn::Path {
+        match self.variant {
+            Some(ref variant) => {
+                let variant_ident = &variant.ident;
+                syn::parse_quote!(Self::#variant_ident)
+            }
+            None => syn::parse_quote!(Self),
+        }
+    }
+
+    /// Returns the name of the struct or the enum variant, that is used in the documentation.
+    pub(crate) fn doc_name(&self) -> String {
+        match self.variant {
+            Some(ref variant) => format!("{}::{}", self.ident, variant.ident),
+            None => self.ident.to_string(),
+        }
+    }
+
+    /// Returns the documentation of the given generated function, when the struct attribute doesn't have the `doc` option.
+    /// It has an `# Arguments` section from the arguments fields documentation,
+    /// and a `# Defaults` section with the values of the fields that are not arguments.
+    pub(crate) fn default_doc(&self, constructor: &Constructor) -> String {
+        let doc_name = self.doc_name();
+        let mut doc = format!(" Creates a new [`{}`] instance.", doc_name);
+        if self.is_non_exhaustive {
+            doc.push_str(&format!(
+                "\n\n [`{}`] is `#[non_exhaustive]`, so it can't be created with a struct expression \
+                 outside of its crate, this function is the way to create it.",
+                doc_name
+            ));
+        }
+        if !constructor.args.is_empty() {
+            doc.push_str("\n\n # Arguments\n");
+            for arg in &constructor.args {
+                doc.push_str(&format!("\n * `{}`", arg.name));
+                let field_doc = self.fields[arg.field_idx]
+                    .docs
+                    .iter()
+                    .map(|line| line.trim())
+                    .collect::<Vec<_>>()
+                    .join("\n   ");
+                if !field_doc.trim().is_empty() {
+                    doc.push_str(" - ");
+                    doc.push_str(field_doc.trim());
+                }
+            }
+        }
+        let defaults: Vec<String> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| constructor.arg(*idx).is_none())
+            .filter_map(|(idx, field)| {
+                let name = field
+                    .param_name()
+                    .map_or_else(|| idx.to_string(), |name| name.to_string());
+                field
+                    .fallback_doc()
+                    .map(|value| format!("\n * `{}`: `{}`", name, value))
+            })
+            .collect();
+        if !defaults.is_empty() {
+            doc.push_str("\n\n # Defaults\n\n The fields that are not arguments are set to:\n");
+            doc.push_str(&defaults.concat());
+        }
+        doc
+    }
+
+    /// Returns the names of the local variables that hold the fields values in the generated functions.
+    pub(crate) fn locals(&self) -> Vec<Ident> {
+        (0..self.fields.len())
+            .map(|idx| quote::format_ident!("__impl_new_{}", idx))
+            .collect()
+    }
+
+    /// Returns the instance creation expression from the given fields values.
+    pub(crate) fn instance(&self, locals: &[Ident]) -> TokenStream {
+        let self_path = self.self_path();
+        if self.is_unit {
+            quote!(#self_path)
+        } else if self.is_tuple_struct {
+            quote!(#self_path(#(#locals),*))
+        } else {
+            let names = self.fields.iter().map(|field| field.field_name());
+            quote!(#self_path { #(#names: #locals),* })
+        }
+    }
+
+    /// Returns true if the arguments are converted with `Into::into` by default.
+    /// The `into` option of the struct attribute if set, otherwise true if the function is not `const`.
+    pub(crate) fn is_into(&self) -> bool {
+        self.attr
+            .into
+            .as_ref()
+            .map_or(!self.is_const(), |into| *into.as_ref())
+    }
+
+    /// Returns true if the generated functions are `const`.
+    pub(crate) fn is_const(&self) -> bool {
+        self.attr.is_const.is_present()
+    }
+
+    /// Returns true if the generated functions return a `Result`.
+    /// That's when the struct attribute has the `try_new` or `validate` option,
+    /// or a field has the `try_into` or `validate` option.
+    pub(crate) fn is_fallible(&self) -> bool {
+        self.attr.try_new.is_present()
+            || self.attr.validate.is_some()
+            || self
+                .fields
+                .iter()
+                .any(|field| field.is_try_into() || field.validator().is_some())
+    }
+
+    /// Returns true if the validation errors are collected instead of returning the first one.
+    pub(crate) fn is_validate_all(&self) -> bool {
+        self.attr.validate_all.is_present()
+    }
+
+    /// Returns the error type of the `Result` returned by the fallible functions.
+    /// The error type itself, or a `Vec` of it if the struct attribute has the `validate_all` option.
+    pub(crate) fn result_error_type(&self) -> syn::Type {
+        let error_type = self.error_type();
+        if self.is_validate_all() {
+            syn::parse_quote!(::std::vec::Vec<#error_type>)
+        } else {
+            error_type
+        }
+    }
+
+    /// Returns the function that maps an error to the error type, with the given generated error variant.
+    pub(crate) fn map_err(&self, error_variant: &Ident) -> syn::Expr {
+        if self.attr.error.is_some() {
+            let error_type = self.error_type();
+            syn::parse_quote!(::core::convert::Into::<#error_type>::into)
+        } else {
+            let error_ident = utils::error_ident(&self.ident);
+            syn::parse_quote!(|error| #error_ident::#error_variant(::core::convert::Into::into(error)))
+        }
+    }
+
+    /// Returns the given result expression with its error mapped to the error type of the function, and propagated with `?`.
+    pub(crate) fn try_expr(&self, expr: syn::Expr, error_variant: &Ident) -> syn::Expr {
+        let map_err = self.map_err(error_variant);
+        if self.is_validate_all() {
+            syn::parse_quote!(#expr.map_err(|error| ::std::vec![(#map_err)(error)])?)
+        } else {
+            syn::parse_quote!(#expr.map_err(#map_err)?)
+        }
+    }
+
+    /// Returns the error type of the fallible functions.
+    /// The `error` option of the struct attribute if set, otherwise the generated error type.
+    pub(crate) fn error_type(&self) -> syn::Type {
+        match self.attr.error {
+            Some(ref error) => error.as_ref().clone(),
+            None => {
+                let error_ident = utils::error_ident(&self.ident);
+                syn::parse_quote!(#error_ident)
+            }
+        }
+    }
+
+    /// Returns the type of the argument of the given field.
+    pub(crate) fn arg_type(&self, field: &ImplNewField) -> syn::Type {
+        let ty = &field.ty;
+        match field.conversion(self.is_into()) {
+            Conversion::None => ty.clone(),
+            Conversion::Into => syn::parse_quote!(impl Into<#ty>),
+            Conversion::TryInto => {
+                let error_target: syn::Type = if self.attr.error.is_some() {
+                    self.error_type()
+                } else {
+                    syn::parse_quote!(
+                        ::std::boxed::Box<
+                            dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync,
+                        >
+                    )
+                };
+                syn::parse_quote!(
+                    impl ::core::convert::TryInto<#ty, Error = impl ::core::convert::Into<#error_target>>
+                )
+            }
+        }
+    }
+
+    /// Returns the value of the given field when its an argument, the argument after the conversion.
+    pub(crate) fn arg_value(&self, field: &ImplNewField) -> syn::Expr {
+        let param_name = field.param_name();
+        match field.conversion(self.is_into()) {
+            Conversion::None => syn::parse_quote!(#param_name),
+            Conversion::Into => syn::parse_quote!(#param_name.into()),
+            Conversion::TryInto => {
+                let error_variant = utils::error_variant_ident(
+                    param_name.as_ref().expect("Arguments always have a name."),
+                );
+                self.try_expr(
+                    syn::parse_quote!(::core::convert::TryInto::try_into(#param_name)),
+                    &error_variant,
+                )
+            }
+        }
+    }
+
+    /// Returns the value of the given field that uses the `default` or `value` option.
+    /// The closure parameters are bound by reference to the fields values with the same argument name.
+    pub(crate) fn fallback_value(&self, field: &ImplNewField, locals: &[Ident]) -> TokenStream {
+        if let Some(closure) = field.fallback_closure() {
+            return self.closure_call(closure, locals);
+        }
+        let value = field
+            .fallback_value()
+            .expect("The field is checked to have a fallback.");
+        let params = field.fallback_params();
+        if params.is_empty() {
+            return quote!(#value);
+        }
+        let bindings = params.iter().map(|param| {
+            let idx = utils::closure_param_ident(param)
+                .and_then(|ident| self.arg_field_index(ident))
+                .expect("The closure parameters are checked to be arguments.");
+            let local = &locals[idx];
+            quote!(let #param = &#local;)
+        });
+        quote!({ #(#bindings)* #value })
+    }
+
+    /// Returns the call of the given `value` or `default = <VALUE>` closure, with the arguments values of its parameters.
+    /// The parameters without a type are given the reference type of their fields, so the closure can be type checked.
+    fn closure_call(&self, closure: &syn::ExprClosure, locals: &[Ident]) -> TokenStream {
+        let mut closure = closure.clone();
+        let mut args = Vec::new();
+        for param in closure.inputs.iter_mut() {
+            let idx = utils::closure_param_ident(param)
+                .and_then(|ident| self.arg_field_index(ident))
+                .expect("The closure parameters are checked to be arguments.");
+            let local = &locals[idx];
+            args.push(quote!(&#local));
+            if let syn::Pat::Ident(_) = param {
+                let ty = &self.fields[idx].ty;
+                *param = syn::Pat::Type(syn::PatType {
+                    attrs: Vec::new(),
+                    pat: Box::new(param.clone()),
+                    colon_token: Default::default(),
+                    ty: syn::parse_quote!(&#ty),
+                });
+            }
+        }
+        quote!({
+            #[allow(clippy::redundant_closure_call)]
+            let __impl_new_value = (#closure)(#(#args),*);
+            __impl_new_value
+        })
+    }
+
+    /// Returns the index of the field without a fallback that has the given argument name.
+    pub(crate) fn arg_field_index(&self, ident: &Ident) -> Option<usize> {
+        self.field_index(&syn::Path::from(ident.clone()))
+            .filter(|idx| !self.fields[*idx].has_fallback())
+    }
+
+    /// Returns the fields indexes in the order their values are bound.
+    /// The fields without a fallback first, so the fallback values can read them.
+    pub(crate) fn binding_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.fields.len()).collect();
+        order.sort_by_key(|idx| self.fields[*idx].has_fallback());
+        order
+    }
+
+    /// Returns the fields that have a `with_<field>` function, the fields with a fallback
+    /// that use the `with` option, or all of them if the struct uses it.
+    pub(crate) fn with_fields(&self) -> Vec<&ImplNewField> {
+        self.fields
+            .iter()
+            .filter(|field| {
+                field.has_fallback() && (self.attr.with.is_present() || field.is_with())
+            })
+            .collect()
+    }
+
+    /// Returns the argument of the field at the given index.
+    pub(crate) fn argument(&self, field_idx: usize) -> Argument {
+        let field = &self.fields[field_idx];
+        Argument {
+            field_idx,
+            name: field
+                .param_name()
+                .expect("The arguments are checked to have a name."),
+            field_ty: field.ty.clone(),
+            ty: self.arg_type(field),
+            conversion: field.conversion(self.is_into()),
+            value: self.arg_value(field),
+        }
+    }
+
+    /// Returns the functions to generate.
+    /// The `constructor` options of the struct attribute if set, otherwise one function named by [`NewStruct::fn_name`].
+    pub(crate) fn constructors(&self) -> Vec<Constructor> {
+        if self.attr.constructors.is_empty() {
+            return vec![Constructor {
+                ident: self.fn_name(),
+                args: self
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, field)| field.arg_name().is_some())
+                    .map(|(idx, _)| self.argument(idx))
+                    .collect(),
+            }];
+        }
+        self.attr
+            .constructors
+            .iter()
+            .map(|constructor| {
+                let args = match constructor.args {
+                    Some(ref args) => args
+                        .iter()
+                        .filter_map(|arg| self.field_index(arg))
+                        .map(|idx| self.argument(idx))
+                        .collect(),
+                    None => self
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, field)| field.param_name().is_some())
+                        .map(|(idx, _)| self.argument(idx))
+                        .collect(),
+                };
+                Constructor {
+                    ident: Ident::new(&constructor.name, constructor.name.span()),
+                    args,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the index of the field that has the given argument name.
+    pub(crate) fn field_index(&self, arg: &syn::Path) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|field| field.param_name().map_or(false, |name| arg.is_ident(&name)))
+    }
+}
+
+/// A function to generate for the struct.
+pub(crate) struct Constructor {
+    /// The name of the function.
+    pub(crate) ident: Ident,
+    /// The arguments of the function, in the arguments order.
+    pub(crate) args: Vec<Argument>,
+}
+
+impl Constructor {
+    /// Returns the argument that sets the field at the given index, None if the field is not an argument.
+    pub(crate) fn arg(&self, field_idx: usize) -> Option<&Argument> {
+        self.args.iter().find(|arg| arg.field_idx == field_idx)
+    }
+}
+
+/// An argument of a generated function, it sets one field.
+pub(crate) struct Argument {
+    /// The index of the field that the argument sets.
+    pub(crate) field_idx: usize,
+    /// The name of the argument.
+    pub(crate) name: Ident,
+    /// The type of the field.
+    pub(crate) field_ty: syn::Type,
+    /// The type of the argument, e.g. `impl Into<T>`.
+    pub(crate) ty: syn::Type,
+    /// How the argument is converted to the field type.
+    pub(crate) conversion: Conversion,
+    /// The field value, the argument after the conversion.
+    pub(crate) value: syn::Expr,
+}
+
+/// An enum variant to generate the function for.
+pub(crate) struct NewVariant {
+    /// The name of the variant.
+    pub(crate) ident: syn::Ident,
+    /// Whether the variant fields are unnamed.
+    pub(crate) is_tuple: bool,
+    /// Whether the variant has no fields.
+    pub(crate) is_unit: bool,
+    /// Whether the variant has the `#[non_exhaustive]` attribute.
+    pub(crate) is_non_exhaustive: bool,
+    /// `#[impl_new(...)]` attribute of the variant.
+    pub(crate) attr: ImplNewVariantAttr,
+    pub(crate) span: Span,
+}
+
+impl NewVariant {
+    pub(crate) fn new(variant: &syn::Variant, attr: ImplNewVariantAttr) -> Self {
+        Self {
+            ident: variant.ident.clone(),
+            is_tuple: matches!(variant.fields, syn::Fields::Unnamed(_)),
+            is_unit: matches!(variant.fields, syn::Fields::Unit),
+            is_non_exhaustive: utils::is_non_exhaustive(&variant.attrs),
+            attr,
+            span: variant.span(),
+        }
+    }
 }
@@ -0,0 +1,86 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{fields::Conversion, new_struct::NewStruct, utils};
+
+/// Generates `impl From<(A, B, ...)>` for the struct, calling its first generated function.
+pub(crate) fn from_tuple(new_struct: &NewStruct) -> TokenStream {
+    let struct_ident = &new_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = new_struct.generics.split_for_impl();
+    let constructor = new_struct
+        .constructors()
+        .into_iter()
+        .next()
+        .expect("There is at least one constructor.");
+    let fn_name = &constructor.ident;
+    let arg_names: Vec<_> = constructor.args.iter().map(|arg| &arg.name).collect();
+    let types: Vec<_> = constructor.args.iter().map(|arg| &arg.field_ty).collect();
+
+    quote! {
+        impl #impl_generics ::core::convert::From<(#(#types,)*)> for #struct_ident #ty_generics #where_clause {
+            fn from((#(#arg_names,)*): (#(#types,)*)) -> Self {
+                Self::#fn_name(#(#arg_names),*)
+            }
+        }
+    }
+}
+
+/// Generates `impl From<T>` for the struct with a single argument, calling its first generated function.
+/// `T` is any type that implements `Into<A>`, or the argument type itself if the argument is not converted.
+/// If the argument type uses a type parameter of the struct, `T` is the argument type itself too,
+/// `From<T> where T: Into<A>` would conflict with `impl<T> From<T> for T`.
+pub(crate) fn from_arg(new_struct: &NewStruct) -> TokenStream {
+    let struct_ident = &new_struct.ident;
+    let (_, ty_generics, where_clause) = new_struct.generics.split_for_impl();
+    let constructor = new_struct
+        .constructors()
+        .into_iter()
+        .next()
+        .expect("There is at least one constructor.");
+    let fn_name = &constructor.ident;
+    let arg = &constructor.args[0];
+    let ty = &arg.field_ty;
+
+    let mut generics = new_struct.generics.clone();
+    let arg_type = if arg.conversion == Conversion::Into
+        && !utils::uses_type_param(ty, &new_struct.generics)
+    {
+        generics
+            .params
+            .push(syn::parse_quote!(__ImplNewArg: ::core::convert::Into<#ty>));
+        quote!(__ImplNewArg)
+    } else {
+        quote!(#ty)
+    };
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::core::convert::From<#arg_type> for #struct_ident #ty_generics #where_clause {
+            fn from(arg: #arg_type) -> Self {
+                Self::#fn_name(arg)
+            }
+        }
+    }
+}
+
+/// Generates `impl Default` for the struct, with the `default` and `value` options values of its fields.
+pub(crate) fn default_impl(new_struct: &NewStruct) -> TokenStream {
+    let struct_ident = &new_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = new_struct.generics.split_for_impl();
+    let locals = new_struct.locals();
+    let bindings = new_struct.fields.iter().zip(&locals).map(|(field, local)| {
+        let ty = &field.ty;
+        let value = new_struct.fallback_value(field, &locals);
+        quote!(let #local: #ty = #value;)
+    });
+    let instance = new_struct.instance(&locals);
+
+    quote! {
+        impl #impl_generics ::core::default::Default for #struct_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                #(#bindings)*
+                #instance
+            }
+        }
+    }
+}
@@ -1,14 +1,25 @@
-use proc_macro2::Span;
+use darling::util::SpannedValue;
+use proc_macro2::{Span, TokenStream, TokenTree};
 use proc_macro_error::{abort, Diagnostic, Level};
+use quote::ToTokens;
 use syn::spanned::Spanned;
 
-use crate::{attrs::ImplNewAttr, fields::ImplNewField};
+use crate::{
+    attrs::{ImplNewAttr, ImplNewContainerAttr, OptionalMode},
+    fields::{Conversion, ImplNewField},
+    getters::GetterKind,
+    new_struct::NewStruct,
+};
 
 /// Returns the span of the invalid derive input, if its not a struct.
 pub(crate) fn is_struct(ast: &syn::DeriveInput) -> bool {
     matches!(ast.data, syn::Data::Struct(_))
 }
 
+pub(crate) fn is_enum(ast: &syn::DeriveInput) -> bool {
+    matches!(ast.data, syn::Data::Enum(_))
+}
+
 pub(crate) fn is_tuple_struct(ast: &syn::DeriveInput) -> bool {
     matches!(
         ast.data,
@@ -19,46 +30,274 @@ pub(crate) fn is_tuple_struct(ast: &syn::DeriveInput) -> bool {
     )
 }
 
-/// Checks if the attribute exists, and returns the span of the attribute.
-pub(crate) fn is_path_exist(path: &str, attrs: &[syn::Attribute]) -> Option<Span> {
-    for attr in attrs {
-        if let syn::Meta::List(ref meta_list) = attr.meta {
-            if meta_list.path.is_ident(path) {
-                return Some(attr.meta.span());
-            }
-        }
-    }
-    None
+/// Returns true if the struct has no fields, `struct Marker;`.
+pub(crate) fn is_unit_struct(ast: &syn::DeriveInput) -> bool {
+    matches!(
+        ast.data,
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unit,
+            ..
+        })
+    )
+}
+
+/// Returns true if the attributes have the `#[non_exhaustive]` attribute.
+pub(crate) fn is_non_exhaustive(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("non_exhaustive"))
 }
 
 /// Run checks on the derive input. Will abort if the input is invalid.
 pub(crate) fn derive_input_checks(ast: &syn::DeriveInput) {
-    if !is_struct(ast) {
+    if !is_struct(ast) && !is_enum(ast) {
         abort!(
             ast,
-            "The `impl_new::New` macro can only be used on structs."
+            "The `impl_new::New` macro can only be used on structs and enums."
         );
-    } else if let Some(sp) = is_path_exist("impl_new", &ast.attrs) {
-        abort!(
-            sp,
-            "The `impl_new` attribute is not supported on the struct itself.";
-            help = "The `impl_new` attribute is only supported on the fields."
-        )
     }
 }
 
+/// Converts the given `snake_case` name to `PascalCase`.
+pub(crate) fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Returns the name of the generated error type of the given struct.
+pub(crate) fn error_ident(struct_ident: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(&format!("{}Error", struct_ident), struct_ident.span())
+}
+
+/// Returns the name of the generated error variant of the given argument.
+pub(crate) fn error_variant_ident(arg_name: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(
+        &to_pascal_case(arg_name.to_string().trim_start_matches("r#")),
+        arg_name.span(),
+    )
+}
+
+/// Returns the name of the generated error variant of the struct `validate` option.
+pub(crate) fn container_error_variant_ident() -> syn::Ident {
+    syn::Ident::new("Invalid", Span::call_site())
+}
+
+/// Converts the given `PascalCase` name to `snake_case`.
+pub(crate) fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake_case = String::with_capacity(name.len());
+    for (idx, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && idx != 0 {
+            let prev = chars[idx - 1];
+            let next_is_lower = chars.get(idx + 1).map_or(false, |next| next.is_lowercase());
+            if prev != '_' && (!prev.is_uppercase() || next_is_lower) {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(c.to_lowercase());
+    }
+    snake_case
+}
+
+/// Returns the source-like string of the given expression, used in the generated documentation.
+pub(crate) fn expr_to_doc(expr: &syn::Expr) -> String {
+    tokens_to_doc(quote::quote!(#expr))
+}
+
+/// The kind of the last token written by [`tokens_to_doc`], it decides if a space is put before the next token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocToken {
+    /// The start of the tokens, or the start of a group.
+    Start,
+    /// A token that is joined with the next one, e.g. `.`, `::`, a unary operator or a joint punctuation.
+    Joined,
+    /// An identifier that isn't a keyword, or a literal.
+    Word,
+    /// A keyword identifier, e.g. `as`, `return` or `move`.
+    Keyword,
+    /// A closed group, or a closing `>` or `|`.
+    Closed,
+    /// A binary operator or a separator.
+    Operator,
+    /// A punctuation of a multi-character operator that isn't finished, e.g. the first `&` of `&&`.
+    OperatorPart,
+}
+
+/// Returns the string of the given tokens as `rustfmt` would write them on one line.
+/// The literals are written as they are, and the joint punctuations (multi-character operators) are not split.
+fn tokens_to_doc(tokens: TokenStream) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "dyn", "else", "for", "if", "impl", "in", "let",
+        "loop", "match", "move", "mut", "ref", "return", "static", "unsafe", "where", "while",
+    ];
+    let mut doc = String::new();
+    let mut last = DocToken::Start;
+    let mut generics_depth = 0usize;
+    let mut in_closure_params = false;
+    // In a type (after `as`, `->` or a `let` or closure parameter `:`), a `<` after a name opens generics.
+    let mut in_type = false;
+    let mut in_let = false;
+    for token in tokens {
+        let no_space = matches!(last, DocToken::Start | DocToken::Joined);
+        match token {
+            proc_macro2::TokenTree::Ident(ident) => {
+                if !no_space {
+                    doc.push(' ');
+                }
+                let ident = ident.to_string();
+                match ident.as_str() {
+                    "as" => in_type = true,
+                    "let" => in_let = true,
+                    _ => {}
+                }
+                last = if KEYWORDS.contains(&ident.as_str()) {
+                    DocToken::Keyword
+                } else {
+                    DocToken::Word
+                };
+                doc.push_str(&ident);
+            }
+            proc_macro2::TokenTree::Literal(literal) => {
+                if !no_space {
+                    doc.push(' ');
+                }
+                doc.push_str(&literal.to_string());
+                last = DocToken::Word;
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let inner = tokens_to_doc(group.stream());
+                let (open, close) = match group.delimiter() {
+                    proc_macro2::Delimiter::Parenthesis => ("(", ")"),
+                    proc_macro2::Delimiter::Bracket => ("[", "]"),
+                    proc_macro2::Delimiter::Brace if inner.is_empty() => ("{", "}"),
+                    proc_macro2::Delimiter::Brace => ("{ ", " }"),
+                    proc_macro2::Delimiter::None => ("", ""),
+                };
+                let is_call = open != "{ "
+                    && open != "{"
+                    && matches!(last, DocToken::Word | DocToken::Closed);
+                if !no_space && !is_call {
+                    doc.push(' ');
+                }
+                doc.push_str(open);
+                doc.push_str(&inner);
+                doc.push_str(close);
+                last = DocToken::Closed;
+                if generics_depth == 0 {
+                    in_type = false;
+                }
+            }
+            proc_macro2::TokenTree::Punct(punct) => {
+                let c = punct.as_char();
+                let is_joint = punct.spacing() == proc_macro2::Spacing::Joint;
+                let is_unary = matches!(
+                    last,
+                    DocToken::Start | DocToken::Joined | DocToken::Operator | DocToken::Keyword
+                );
+                let (space_before, next) = match c {
+                    _ if last == DocToken::OperatorPart => (
+                        false,
+                        if is_joint {
+                            DocToken::OperatorPart
+                        } else if doc.ends_with('.') {
+                            // The `..=` range operator is joined with its operands like `..`.
+                            DocToken::Joined
+                        } else {
+                            DocToken::Operator
+                        },
+                    ),
+                    '.' if is_joint => (false, DocToken::OperatorPart),
+                    '.' | ',' | ';' | '?' => (
+                        false,
+                        match c {
+                            '.' => DocToken::Joined,
+                            '?' => DocToken::Closed,
+                            _ => DocToken::Operator,
+                        },
+                    ),
+                    ':' if doc.ends_with(':') && last == DocToken::Joined => {
+                        (false, DocToken::Joined)
+                    }
+                    ':' if is_joint => (
+                        matches!(last, DocToken::Keyword | DocToken::Operator),
+                        DocToken::Joined,
+                    ),
+                    ':' => (false, DocToken::Operator),
+                    '!' if last == DocToken::Word && !is_joint => (false, DocToken::Joined),
+                    '<' if !is_joint
+                        && (is_unary
+                            || doc.ends_with("::")
+                            || (in_type && last == DocToken::Word)) =>
+                    {
+                        generics_depth += 1;
+                        (last != DocToken::Word, DocToken::Joined)
+                    }
+                    '>' if generics_depth > 0 => {
+                        generics_depth -= 1;
+                        (false, DocToken::Closed)
+                    }
+                    '|' if in_closure_params => {
+                        in_closure_params = false;
+                        (false, DocToken::Operator)
+                    }
+                    '|' if !is_joint && is_unary => {
+                        in_closure_params = true;
+                        (true, DocToken::Joined)
+                    }
+                    '\'' | '#' => (true, DocToken::Joined),
+                    _ if is_joint => (true, DocToken::OperatorPart),
+                    '!' | '&' | '*' | '-' if is_unary => (true, DocToken::Joined),
+                    _ => (true, DocToken::Operator),
+                };
+                if space_before && !no_space {
+                    doc.push(' ');
+                }
+                match c {
+                    '>' if doc.ends_with('-') => in_type = true,
+                    ':' if !is_joint && (in_let || in_closure_params) => in_type = true,
+                    '>' if next == DocToken::Closed => {}
+                    '&' | '*' if is_unary => {}
+                    ':' | '<' | '\'' => {}
+                    '=' | ';' => {
+                        in_let = false;
+                        in_type = false;
+                    }
+                    _ if generics_depth == 0 => in_type = false,
+                    _ => {}
+                }
+                doc.push(c);
+                last = next;
+            }
+        }
+    }
+    doc
+}
+
 /// Abort the given error
-pub(crate) fn abort_error(errors: darling::Error, supported_fields: &[&str]) {
-    for err in errors.flatten() {
+pub(crate) fn abort_error(errors: darling::Error, supported_options: &[&str]) {
+    if let Some(err) = errors.flatten().into_iter().next() {
         let error_msg = err.to_string();
         let mut diagnostic = Diagnostic::spanned(err.span(), Level::Error, error_msg.clone());
         if error_msg.contains("Unexpected literal type") && error_msg.contains("name") {
             diagnostic =
                 diagnostic.help("The `name` option only accepts string literals.".to_owned());
+        } else if error_msg.contains("Duplicate") {
+            diagnostic = diagnostic.help(
+                "Remove the duplicate option, the options of all the `impl_new` attributes are merged."
+                    .to_owned(),
+            );
         } else if error_msg.contains("Unknown") || error_msg.contains("Unexpected") {
             diagnostic = diagnostic.help(format!(
-                "Supported field attributes: {}",
-                supported_fields.join(", ")
+                "Supported options: {}",
+                supported_options.join(", ")
             ));
         }
         diagnostic.abort();
@@ -76,20 +315,77 @@ pub(crate) fn abort_error(errors: darling::Error, supported_fields: &[&str]) {
 /// - Checks that the `default` option is not set with the `name` option.
 /// ### `value` option
 /// - Checks that the `value` option is not set with the `name` and `default` option.
-/// - Checks that the `value` option is a closure expression.
+/// ### `try_into` option
+/// - Checks that the `try_into` option is not set with the `into` option.
+/// - Checks that the `try_into` option is not set on unnamed fields without the `name` option.
+/// ### `validate` option
+/// - Checks that the `validate` option is not set on unnamed fields without the `name` option.
+/// ### `optional` option
+/// - See [`optional_checks`].
 pub(crate) fn impl_new_checks(
     ident: &Option<syn::Ident>,
     field_type: &syn::Type,
     impl_new_attr: &Option<ImplNewAttr>,
 ) {
+    if let Some(ImplNewAttr {
+        try_into,
+        into,
+        name,
+        ..
+    }) = impl_new_attr
+    {
+        if try_into.is_present() {
+            if let Some(into) = into {
+                abort!(
+                    into.span(),
+                    "The `into` option cannot be used with the `try_into` option.";
+                    help = "Remove the `into` option.";
+                    note = "The `try_into` option converts the argument with `TryInto::try_into` instead of `Into::into`."
+                )
+            }
+            if ident.is_none() && name.is_none() {
+                abort!(
+                    try_into.span(),
+                    "The `try_into` option requires the `name` option on unnamed fields.";
+                    help = "Add the `name` option."
+                )
+            }
+        }
+    }
+    if let Some(ImplNewAttr {
+        validate: Some(validate),
+        name: None,
+        ..
+    }) = impl_new_attr
+    {
+        if ident.is_none() {
+            abort!(
+                validate.span(),
+                "The `validate` option requires the `name` option on unnamed fields.";
+                help = "Add the `name` option.";
+                note = "The `name` option is used to name the validation error."
+            )
+        }
+    }
+    if let Some(ImplNewAttr {
+        optional: Some(optional),
+        ..
+    }) = impl_new_attr
+    {
+        optional_checks(
+            field_type,
+            optional,
+            impl_new_attr.as_ref().expect("The attribute is set."),
+        );
+    }
     let is_named = ident.is_some();
     if !is_named
         && (impl_new_attr.is_none()
-            || matches!(impl_new_attr, Some(ImplNewAttr { name: None, default, value, .. }) if !default.is_present() && value.is_none()))
+            || matches!(impl_new_attr, Some(ImplNewAttr { name: None, default, value, optional, .. }) if default.is_none() && value.is_none() && !matches!(optional.as_deref(), Some(OptionalMode::Omit))))
     {
         abort!(
             field_type,
-            "Unnamed fields must have the `name`, `default` or `value` option set."
+            "Unnamed fields must have the `name`, `default`, `value` or `optional` option set."
         )
     }
     if let Some(ImplNewAttr {
@@ -99,7 +395,7 @@ pub(crate) fn impl_new_checks(
         ..
     }) = impl_new_attr
     {
-        if default.is_present() {
+        if default.is_some() {
             abort!(
                 name.span(),
                 "The `default` option cannot be used with the `name` option.";
@@ -133,20 +429,92 @@ pub(crate) fn impl_new_checks(
             )
         }
     } else if let Some(ImplNewAttr { default, value, .. }) = impl_new_attr {
-        if default.is_present() && value.is_some() {
+        if default.is_some() && value.is_some() {
             abort!(
                 value.as_ref().unwrap().span(),
                 "The `default` and `value` options cannot be used together.";
                 help = "Remove the `default` or `value` option."
             )
         }
-        if value.is_some() && !matches!(value.as_deref(), Some(syn::Expr::Closure(_))) {
+    }
+}
+
+/// Run checks on the field `optional` option. Will abort if its invalid.
+///
+/// ## Checks
+/// - Checks that the field type is `Option<T>`.
+/// - Checks that the `optional` option is not set with the `default`, `value`, `into` and `try_into` options.
+/// - Checks that the `optional` option without an argument is not set with the `name` option.
+fn optional_checks(
+    field_type: &syn::Type,
+    optional: &SpannedValue<OptionalMode>,
+    attr: &ImplNewAttr,
+) {
+    if !is_option_type(field_type) {
+        abort!(
+            optional.span(),
+            "The `optional` option requires an `Option<T>` field.";
+            help = "Remove the `optional` option, or change the field type to `Option<T>`."
+        )
+    }
+    let conflicts = [
+        (
+            "default",
+            attr.default.as_ref().map(|default| default.span()),
+        ),
+        ("value", attr.value.as_ref().map(|value| value.span())),
+        ("into", attr.into.as_ref().map(|into| into.span())),
+        (
+            "try_into",
+            attr.try_into.is_present().then(|| attr.try_into.span()),
+        ),
+    ];
+    for (option, span) in conflicts {
+        if let Some(span) = span {
             abort!(
-                value.as_ref().unwrap().span(),
-                "The `value` option must be a closure expression."
+                span,
+                "The `{}` option cannot be used with the `optional` option.", option;
+                help = "Remove the `{}` option.", option
             )
         }
     }
+    if let (OptionalMode::Omit, Some(name)) = (**optional, attr.name.as_ref()) {
+        abort!(
+            name.span(),
+            "The `optional` option cannot be used with the `name` option.";
+            help = "Remove the `name` option, or use `optional = \"arg\"`.";
+            note = "The `optional` option will remove the field from the generated `new` function, \
+                    so the `name` option is not needed."
+        )
+    }
+}
+
+/// Returns true if the given type uses one of the type parameters of the given generics.
+pub(crate) fn uses_type_param(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    fn uses(tokens: TokenStream, params: &[&syn::Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => params.contains(&&ident),
+            TokenTree::Group(group) => uses(group.stream(), params),
+            _ => false,
+        })
+    }
+    let params: Vec<&syn::Ident> = generics.type_params().map(|param| &param.ident).collect();
+    !params.is_empty() && uses(ty.to_token_stream(), &params)
+}
+
+/// Returns true if the given type is `Option<T>`.
+pub(crate) fn is_option_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            path.segments.last().map_or(false, |segment| {
+                segment.ident == "Option"
+                    && matches!(segment.arguments, syn::PathArguments::AngleBracketed(ref args) if args.args.len() == 1)
+            })
+        }
+        syn::Type::Group(group) => is_option_type(&group.elem),
+        syn::Type::Paren(paren) => is_option_type(&paren.elem),
+        _ => false,
+    }
 }
 
 /// Run checks on the `impl_new::New` macro struct fields. Will abort if the field is invalid.
@@ -174,3 +542,672 @@ pub(crate) fn new_macro_checks(fields: &[ImplNewField]) {
         }
     }
 }
+
+/// Checks that the given option value is a valid identifier. Will abort if its not.
+fn check_ident_option(value: &SpannedValue<String>, option: &str, note: &str) {
+    if value.is_empty() {
+        abort!(
+            value.span(),
+            "The `{}` option value cannot be empty.", option;
+            help = "Add a value to the `{}` option.", option
+        )
+    } else if syn::parse_str::<syn::Ident>(value.as_ref()).is_err() {
+        abort!(
+            value.span(),
+            "The `{}` option value `{}` is not a valid identifier.",
+            option, value.as_ref();
+            help = "The `{}` option value must be a valid identifier.", option;
+            note = "{}", note
+        )
+    }
+}
+
+/// Run checks on the struct `impl_new` attribute. Will abort if the attribute is invalid.
+///
+/// ## Checks
+/// ### `fn_name` option
+/// - Checks if the `fn_name` option value is not empty.
+/// - Checks if the `fn_name` option value are a valid identifier.
+/// - Checks that the `fn_name` option is not set with the `constructor` option.
+/// ### `constructor` option
+/// - Checks if the `name` value is not empty and is a valid identifier.
+/// - Checks if the `name` value is not duplicated.
+/// ### `must_use` option
+/// - Checks that `must_use = false` is not set with a `must_use` attribute in the `fn_attrs` option.
+/// ### Enums
+/// - Checks that the `fn_name` and `constructor` options are not used on enums.
+pub(crate) fn container_checks(ast: &syn::DeriveInput, container_attr: &ImplNewContainerAttr) {
+    if is_enum(ast) {
+        if let Some(ref fn_name) = container_attr.fn_name {
+            abort!(
+                fn_name.span(),
+                "The `fn_name` option is not supported on enums.";
+                help = "Use the `fn_name` option on the variants instead."
+            )
+        }
+        if let Some(constructor) = container_attr.constructors.first() {
+            abort!(
+                constructor.span(),
+                "The `constructor` option is not supported on enums.";
+                help = "Each variant has its own function, use the `fn_name` option on the variants to rename it."
+            )
+        }
+    }
+    if let Some(ref fn_name) = container_attr.fn_name {
+        check_ident_option(
+            fn_name,
+            "fn_name",
+            "The `fn_name` option is used as the name of the generated function.",
+        );
+        if let Some(constructor) = container_attr.constructors.first() {
+            abort!(
+                fn_name.span(),
+                "The `fn_name` option cannot be used with the `constructor` option.";
+                help = "Remove the `fn_name` option.";
+                span_note = constructor.span() => "The `constructor` option sets the names of the generated functions."
+            )
+        }
+    }
+    let mut names: Vec<&str> = Vec::new();
+    for constructor in &container_attr.constructors {
+        check_ident_option(
+            &constructor.name,
+            "name",
+            "The `name` option is used as the name of the generated function.",
+        );
+        if names.contains(&constructor.name.as_str()) {
+            abort!(
+                constructor.name.span(),
+                "Duplicate constructor name `{}`.",
+                constructor.name.as_str();
+                help = "Each constructor must have a unique name."
+            )
+        }
+        names.push(constructor.name.as_str());
+    }
+    if let Some(must_use) = container_attr
+        .must_use
+        .as_ref()
+        .filter(|must_use| !*must_use.as_ref())
+    {
+        if let Some(meta) = container_attr.fn_attrs.as_ref().and_then(|fn_attrs| {
+            fn_attrs
+                .0
+                .iter()
+                .find(|meta| meta.path().is_ident("must_use"))
+        }) {
+            abort!(
+                must_use.span(),
+                "The `must_use = false` option cannot be used with a `must_use` attribute in the `fn_attrs` option.";
+                help = "Remove one of them.";
+                span_note = meta.span() => "The `must_use` attribute is added to the generated constructors."
+            )
+        }
+    }
+}
+
+/// Run checks on the `constructor` options of the struct attribute. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks if the `args` values are names of the struct fields.
+/// - Checks if the `args` values are not duplicated.
+/// - Checks that the fields that are not in `args` use the `default` or `value` option.
+pub(crate) fn constructors_checks(new_struct: &NewStruct) {
+    for constructor in &new_struct.attr.constructors {
+        let args = match constructor.args {
+            Some(ref args) => args,
+            None => continue,
+        };
+        let mut indexes: Vec<usize> = Vec::new();
+        for arg in args.iter() {
+            let idx = match new_struct.field_index(arg) {
+                Some(idx) => idx,
+                None => abort!(
+                    arg,
+                    "There is no field with the argument name `{}`.",
+                    quote::quote!(#arg);
+                    help = "The `args` values must be the argument names of the fields, the field name or its `name` option."
+                ),
+            };
+            if indexes.contains(&idx) {
+                abort!(
+                    arg,
+                    "Duplicate constructor argument `{}`.",
+                    quote::quote!(#arg);
+                    help = "Remove the duplicate argument."
+                )
+            }
+            indexes.push(idx);
+        }
+        for (idx, field) in new_struct.fields.iter().enumerate() {
+            if !indexes.contains(&idx) && !field.has_fallback() {
+                abort!(
+                    field.span,
+                    "The field is not an argument of the `{}` constructor.",
+                    constructor.name.as_str();
+                    help = "Add the field to the constructor `args`, or use the `default` or `value` option on it.";
+                    span_note = constructor.name.span() => "The constructor is defined here."
+                )
+            }
+        }
+    }
+}
+
+/// Run checks on the `const` option of the struct attribute. Will abort if the fields can't be used in a `const` function.
+///
+/// ## Checks
+/// - Checks that the struct `into` option is not set to true.
+/// - Checks that the fields don't use the `default` option without a value.
+/// - Checks that the fields `into` option is not set to true.
+/// - Checks that the function is not fallible.
+/// - Checks that the `value` and `default = <VALUE>` closures are not called.
+pub(crate) fn const_checks(new_struct: &NewStruct) {
+    if !new_struct.is_const() {
+        return;
+    }
+    let const_span = new_struct.attr.is_const.span();
+    if new_struct.attr.try_new.is_present() {
+        abort!(
+            new_struct.attr.try_new.span(),
+            "The `try_new` option cannot be used with the `const` option.";
+            help = "Remove the `try_new` option.";
+            span_note = const_span => "The `const` option generates a `const` function."
+        )
+    }
+    if let Some(into) = new_struct.attr.into.as_ref().filter(|into| *into.as_ref()) {
+        abort!(
+            into.span(),
+            "The `into` option cannot be used with the `const` option.";
+            help = "Remove the `into` option.";
+            span_note = const_span => "`Into::into` cannot be called in a `const` function."
+        )
+    }
+    for field in &new_struct.fields {
+        if let Some(closure) = field.fallback_closure() {
+            abort!(
+                closure,
+                "A closure with `return` or `?` cannot be used with the `const` option.";
+                help = "Remove the `return` and `?` from the closure, so its body is used as the value.";
+                span_note = const_span => "Closures cannot be called in a `const` function."
+            )
+        }
+        if let Some(ref attr) = field.impl_new_attr {
+            if let Some(default) = attr
+                .default
+                .as_ref()
+                .filter(|default| default.value.is_none())
+            {
+                abort!(
+                    default.span(),
+                    "The `default` option cannot be used with the `const` option.";
+                    help = "Use the `default = <VALUE>` option with a constant expression.";
+                    span_note = const_span => "`Default::default` cannot be called in a `const` function."
+                )
+            }
+            if let Some(into) = attr.into.as_ref().filter(|into| *into.as_ref()) {
+                abort!(
+                    into.span(),
+                    "The `into` option cannot be used with the `const` option.";
+                    help = "Remove the `into` option.";
+                    span_note = const_span => "`Into::into` cannot be called in a `const` function."
+                )
+            }
+            if let Some(optional) = attr
+                .optional
+                .as_ref()
+                .filter(|optional| ***optional == OptionalMode::Arg)
+            {
+                abort!(
+                    optional.span(),
+                    "The `optional = \"arg\"` option cannot be used with the `const` option.";
+                    help = "Remove the `optional` option, or use it without a value.";
+                    span_note = const_span => "`Into::into` cannot be called in a `const` function."
+                )
+            }
+            if attr.try_into.is_present() {
+                abort!(
+                    attr.try_into.span(),
+                    "The `try_into` option cannot be used with the `const` option.";
+                    help = "Remove the `try_into` option.";
+                    span_note = const_span => "`TryInto::try_into` cannot be called in a `const` function."
+                )
+            }
+        }
+    }
+}
+
+/// Run checks on the enum variants `impl_new` attributes. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks if the `fn_name` option value is not empty and is a valid identifier.
+/// - Checks if the functions names are not duplicated.
+pub(crate) fn variants_checks(new_structs: &[NewStruct]) {
+    let mut names: Vec<String> = Vec::new();
+    for new_struct in new_structs {
+        if let Some(ref variant) = new_struct.variant {
+            if let Some(ref fn_name) = variant.attr.fn_name {
+                check_ident_option(
+                    fn_name,
+                    "fn_name",
+                    "The `fn_name` option is used as the name of the generated function.",
+                );
+            }
+            let fn_name = new_struct.fn_name();
+            if names.contains(&fn_name.to_string()) {
+                abort!(
+                    fn_name,
+                    "Duplicate function name `{}`.",
+                    fn_name;
+                    help = "Use the `fn_name` option on the variant to rename its function, or the `skip` option to skip it."
+                )
+            }
+            names.push(fn_name.to_string());
+        }
+    }
+}
+
+/// Run checks on the fallible functions. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks that the `error` option is not set if there is no fallible function.
+pub(crate) fn fallible_checks(new_structs: &[NewStruct]) {
+    if let Some(error) = new_structs
+        .first()
+        .and_then(|new_struct| new_struct.attr.error.as_ref())
+    {
+        if !new_structs.iter().any(NewStruct::is_fallible) {
+            abort!(
+                error.span(),
+                "The `error` option is only used by fallible functions.";
+                help = "Add the `try_new` option, or use the `try_into` option on a field."
+            )
+        }
+    }
+}
+
+/// Run checks on the `validate` and `validate_all` options. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks that the `validate_all` option is used with a field `validate` option.
+/// - Checks that the `validate` options are not used with the `const` option.
+/// - Checks that the generated error variants of the arguments don't collide with each other.
+/// - Checks that the generated error variant of the struct `validate` option doesn't collide with a field variant.
+pub(crate) fn validate_checks(new_structs: &[NewStruct]) {
+    let attr = match new_structs.first() {
+        Some(new_struct) => &new_struct.attr,
+        None => return,
+    };
+    let mut validated_fields = new_structs
+        .iter()
+        .flat_map(|new_struct| new_struct.fields.iter())
+        .filter(|field| field.validator().is_some());
+    if attr.validate_all.is_present() && validated_fields.clone().next().is_none() {
+        abort!(
+            attr.validate_all.span(),
+            "The `validate_all` option requires a field with the `validate` option.";
+            help = "Remove the `validate_all` option."
+        )
+    }
+    if attr.is_const.is_present() {
+        let validator_span = attr
+            .validate
+            .as_ref()
+            .map(|validate| validate.span())
+            .or_else(|| {
+                validated_fields.next().and_then(|field| {
+                    field
+                        .impl_new_attr
+                        .as_ref()
+                        .and_then(|attr| attr.validate.as_ref())
+                        .map(|validate| validate.span())
+                })
+            });
+        if let Some(span) = validator_span {
+            abort!(
+                span,
+                "The `validate` option cannot be used with the `const` option.";
+                help = "Remove the `validate` option.";
+                span_note = attr.is_const.span() => "The `const` option generates a `const` function."
+            )
+        }
+    }
+    if attr.error.is_none() {
+        let mut arg_names: Vec<syn::Ident> = Vec::new();
+        for arg_name in new_structs
+            .iter()
+            .flat_map(|new_struct| new_struct.fields.iter())
+            .filter(|field| field.is_try_into() || field.validator().is_some())
+            .filter_map(ImplNewField::param_name)
+        {
+            let variant = error_variant_ident(&arg_name);
+            if let Some(other_name) = arg_names.iter().find(|other_name| {
+                *other_name != &arg_name && error_variant_ident(other_name) == variant
+            }) {
+                abort!(
+                    arg_name,
+                    "The `{}` argument error variant collides with the `{}` argument error variant `{}`.",
+                    arg_name,
+                    other_name,
+                    variant;
+                    help = "Rename one of the arguments with the `name` option, or use the `error` option to set your own error type.";
+                    span_note = other_name.span() => "The `{}` argument adds the `{}` variant to the generated error.", other_name, variant
+                )
+            }
+            if !arg_names.contains(&arg_name) {
+                arg_names.push(arg_name);
+            }
+        }
+        if let Some(validate) = &attr.validate {
+            let container_variant = container_error_variant_ident();
+            if let Some(arg_name) = arg_names
+                .iter()
+                .find(|arg_name| error_variant_ident(arg_name) == container_variant)
+            {
+                abort!(
+                    arg_name,
+                    "The `{}` argument error variant collides with the `validate` option error variant `{}`.",
+                    arg_name,
+                    container_variant;
+                    help = "Rename the argument with the `name` option, or use the `error` option to set your own error type.";
+                    span_note = validate.span() => "The struct `validate` option adds the `{}` variant to the generated error.", container_variant
+                )
+            }
+        }
+    }
+}
+
+/// Run checks on the `builder` option. Will abort if its invalid.
+///
+/// ## Checks
+/// - Checks that the `builder` option is not used on enums.
+/// - Checks that the `builder` option is not used with fallible functions.
+pub(crate) fn builder_checks(ast: &syn::DeriveInput, new_structs: &[NewStruct]) {
+    let attr = match new_structs.first() {
+        Some(new_struct) if new_struct.attr.builder.is_present() => &new_struct.attr,
+        _ => return,
+    };
+    if is_enum(ast) {
+        abort!(
+            attr.builder.span(),
+            "The `builder` option is not supported on enums."
+        )
+    }
+    if new_structs.iter().any(NewStruct::is_fallible) {
+        abort!(
+            attr.builder.span(),
+            "The `builder` option cannot be used with fallible functions.";
+            help = "Remove the `builder` option, or the `try_new`, `try_into` and `validate` options.";
+            note = "The `build` function of the builder is infallible."
+        )
+    }
+}
+
+/// Returns the identifier of the given closure parameter, None if it's not an identifier pattern.
+pub(crate) fn closure_param_ident(param: &syn::Pat) -> Option<&syn::Ident> {
+    match param {
+        syn::Pat::Ident(pat) if pat.subpat.is_none() => Some(&pat.ident),
+        syn::Pat::Type(pat) => closure_param_ident(&pat.pat),
+        _ => None,
+    }
+}
+
+/// Run checks on the `value` and `default = <VALUE>` options closures. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks that the closure parameters are identifiers.
+/// - Checks that the closure parameters are the argument names of fields without a fallback.
+pub(crate) fn fallback_checks(new_struct: &NewStruct) {
+    for field in &new_struct.fields {
+        for param in field.fallback_params() {
+            let ident = match closure_param_ident(param) {
+                Some(ident) => ident,
+                None => abort!(
+                    param,
+                    "The closure parameters must be identifiers.";
+                    help = "Use the argument name of a field as the parameter."
+                ),
+            };
+            if new_struct.arg_field_index(ident).is_none() {
+                abort!(
+                    ident,
+                    "There is no argument named `{}`.", ident;
+                    help = "The closure parameters must be the argument names of the fields, the field name or its `name` option.";
+                    note = "The fields that use the `default` or `value` option cannot be closure parameters."
+                )
+            }
+        }
+    }
+}
+
+/// Run checks on the `from_tuple` and `from_arg` options. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks that the options are not used on enums.
+/// - Checks that the options are not used with fallible functions.
+/// - Checks that the `from_arg` option is used with a function that takes a single argument.
+/// - Checks that the `from_arg` option is not used with the `from_tuple` option if the argument is converted.
+pub(crate) fn from_checks(ast: &syn::DeriveInput, new_structs: &[NewStruct]) {
+    let new_struct = match new_structs.first() {
+        Some(new_struct) => new_struct,
+        None => return,
+    };
+    let attr = &new_struct.attr;
+    for (option, flag) in [
+        ("from_tuple", &attr.from_tuple),
+        ("from_arg", &attr.from_arg),
+    ] {
+        if !flag.is_present() {
+            continue;
+        }
+        if is_enum(ast) {
+            abort!(
+                flag.span(),
+                "The `{}` option is not supported on enums.",
+                option
+            )
+        }
+        if new_struct.is_fallible() {
+            abort!(
+                flag.span(),
+                "The `{}` option cannot be used with fallible functions.", option;
+                help = "Remove the `{}` option, or the `try_new`, `try_into` and `validate` options.", option;
+                note = "`From::from` is infallible."
+            )
+        }
+    }
+    if attr.from_arg.is_present() {
+        let args = new_struct
+            .constructors()
+            .into_iter()
+            .next()
+            .map_or_else(Vec::new, |constructor| constructor.args);
+        if args.len() != 1 {
+            abort!(
+                attr.from_arg.span(),
+                "The `from_arg` option requires a single argument, but the function takes {}.", args.len();
+                help = "Use the `from_tuple` option instead."
+            )
+        }
+        if attr.from_tuple.is_present() && args[0].conversion == Conversion::Into {
+            abort!(
+                attr.from_arg.span(),
+                "The `from_arg` option cannot be used with the `from_tuple` option when the argument is converted with `Into::into`.";
+                help = "Remove one of them, or set the `into` option to false.";
+                note = "`From<T> where T: Into<A>` conflicts with `From<(A,)>`."
+            )
+        }
+    }
+}
+
+/// Run checks on the `impl_default` option. Will abort if its invalid.
+///
+/// ## Checks
+/// - Checks that the `impl_default` option is not used on enums.
+/// - Checks that the `impl_default` option is not used with fallible functions.
+/// - Checks that all the fields use the `default` or `value` option.
+pub(crate) fn impl_default_checks(ast: &syn::DeriveInput, new_structs: &[NewStruct]) {
+    let new_struct = match new_structs.first() {
+        Some(new_struct) if new_struct.attr.impl_default.is_present() => new_struct,
+        _ => return,
+    };
+    let impl_default_span = new_struct.attr.impl_default.span();
+    if is_enum(ast) {
+        abort!(
+            impl_default_span,
+            "The `impl_default` option is not supported on enums."
+        )
+    }
+    if new_struct.is_fallible() {
+        abort!(
+            impl_default_span,
+            "The `impl_default` option cannot be used with fallible functions.";
+            help = "Remove the `impl_default` option, or the `try_new`, `try_into` and `validate` options.";
+            note = "`Default::default` is infallible."
+        )
+    }
+    if let Some(field) = new_struct.fields.iter().find(|field| !field.has_fallback()) {
+        abort!(
+            field.span,
+            "The field must use the `default` or `value` option with the `impl_default` option.";
+            help = "Add the `default` or `value` option to the field.";
+            span_note = impl_default_span => "The `Default` implementation uses the fields `default` and `value` options."
+        )
+    }
+}
+
+/// Run checks on the field and struct `with` options. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks that the `with` option is not used on enums and tuple structs.
+/// - Checks that the fields `with` option is used with the `default`, `value` or `optional` option.
+/// - Checks that the `with` fields don't use the `try_into` and `validate` options.
+/// - Checks that the `with` option is not used with the struct `validate` option.
+pub(crate) fn with_checks(ast: &syn::DeriveInput, new_structs: &[NewStruct]) {
+    for new_struct in new_structs {
+        let with_span = new_struct
+            .attr
+            .with
+            .is_present()
+            .then(|| new_struct.attr.with.span());
+        for field in &new_struct.fields {
+            let attr = match field.impl_new_attr {
+                Some(ref attr) => attr,
+                None => continue,
+            };
+            if attr.with.is_present() && !field.has_fallback() {
+                abort!(
+                    attr.with.span(),
+                    "The `with` option requires the `default`, `value` or `optional` option.";
+                    help = "Remove the `with` option.";
+                    note = "The fields that are arguments of the `new` function don't need a `with_<field>` function."
+                )
+            }
+        }
+        let span = match with_span.or_else(|| {
+            new_struct
+                .with_fields()
+                .first()
+                .and_then(|field| field.impl_new_attr.as_ref())
+                .map(|attr| attr.with.span())
+        }) {
+            Some(span) => span,
+            None => continue,
+        };
+        if is_enum(ast) {
+            abort!(span, "The `with` option is not supported on enums.")
+        }
+        if new_struct.is_tuple_struct {
+            abort!(
+                span,
+                "The `with` option is not supported on tuple structs.";
+                note = "The `with_<field>` functions are named after the fields."
+            )
+        }
+        if let Some(field) = new_struct
+            .with_fields()
+            .into_iter()
+            .find(|field| field.is_try_into() || field.validator().is_some())
+        {
+            abort!(
+                field.span,
+                "The `with` option cannot be used on fields with the `try_into` or `validate` option.";
+                help = "Remove the `try_into` and `validate` options.";
+                span_note = span => "The `with_<field>` functions are infallible."
+            )
+        }
+        if let Some(ref validate) = new_struct.attr.validate {
+            abort!(
+                span,
+                "The `with` option cannot be used with the struct `validate` option.";
+                help = "Remove the `with` option.";
+                span_note = validate.span() => "The `with_<field>` functions are infallible, the instance would not be validated."
+            )
+        }
+    }
+}
+
+/// Run checks on the field and struct getters options. Will abort if they are invalid.
+///
+/// ## Checks
+/// - Checks that the getters options are not used on enums.
+/// - Checks that only one of the `get`, `get_copy` and `get_clone` options is used.
+/// - Checks that the getters options are not used on unnamed fields without the `name` option.
+pub(crate) fn getters_checks(ast: &syn::DeriveInput, new_structs: &[NewStruct]) {
+    for new_struct in new_structs {
+        let attr = &new_struct.attr;
+        let mut spans = vec![GetterKind::from_flags(
+            &attr.get,
+            &attr.get_copy,
+            &attr.get_clone,
+        )];
+        if attr.get_mut.is_present() {
+            spans.push(vec![(GetterKind::Ref, attr.get_mut.span())]);
+        }
+        for field in &new_struct.fields {
+            let field_attr = match field.impl_new_attr {
+                Some(ref field_attr) => field_attr,
+                None => continue,
+            };
+            let kinds = GetterKind::from_flags(
+                &field_attr.get,
+                &field_attr.get_copy,
+                &field_attr.get_clone,
+            );
+            if field.param_name().is_none() {
+                let span = kinds.first().map(|(_, span)| *span).or_else(|| {
+                    field_attr
+                        .get_mut
+                        .is_present()
+                        .then(|| field_attr.get_mut.span())
+                });
+                if let Some(span) = span {
+                    abort!(
+                        span,
+                        "The getters options require the `name` option on unnamed fields.";
+                        help = "Add the `name` option.";
+                        note = "The getters are named by the argument name of the field."
+                    )
+                }
+            }
+            spans.push(kinds);
+            if field_attr.get_mut.is_present() {
+                spans.push(vec![(GetterKind::Ref, field_attr.get_mut.span())]);
+            }
+        }
+        for kinds in &spans {
+            if let [(first, _), (second, span), ..] = kinds[..] {
+                abort!(
+                    span,
+                    "The `{}` option cannot be used with the `{}` option.", second.option(), first.option();
+                    help = "Remove one of them.";
+                    note = "They generate a getter with the same name."
+                )
+            }
+        }
+        if is_enum(ast) {
+            if let Some((_, span)) = spans.iter().flatten().next() {
+                abort!(*span, "The getters options are not supported on enums.")
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::Debug;
+
+#[test]
+fn required_fields() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test {
+        name: String,
+        age: u8,
+    }
+
+    let test = Test::builder().name("Awiteb").age(20).build();
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+
+    // The setters can be called in any order.
+    let test = Test::builder().age(20).name("Awiteb").build();
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+
+    // The `new` function is still generated.
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
+
+#[test]
+fn optional_fields() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        age: u8,
+        #[impl_new(value = || "Unknown".to_owned())]
+        country: String,
+    }
+
+    let test = Test::builder().name("Awiteb").build();
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 0);
+    assert_eq!(test.country, "Unknown".to_owned());
+}
+
+#[test]
+fn set_optional_fields() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        age: u8,
+        #[impl_new(value = || "Unknown".to_owned())]
+        country: String,
+    }
+
+    let test = Test::builder()
+        .age(20)
+        .name("Awiteb")
+        .country("Palestine")
+        .build();
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+    assert_eq!(test.country, "Palestine".to_owned());
+}
+
+#[test]
+fn into_false() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder, into = false)]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        age: u8,
+    }
+
+    let test = Test::builder().name("Awiteb".to_owned()).age(20).build();
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn generics() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test<'a, T: Debug + Default, const N: usize = 2>
+    where
+        T: Clone,
+    {
+        slice: &'a [u8; N],
+        #[impl_new(default)]
+        data: T,
+    }
+
+    let test: Test<'_, String> = Test::builder().slice(&[1, 2]).build();
+    assert_eq!(test.slice, &[1, 2]);
+    assert_eq!(test.data, String::new());
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(default)] u8,
+        #[impl_new(name = "age")] u8,
+    );
+
+    let test = Test::builder().age(20).name("Awiteb").build();
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, 0);
+    assert_eq!(test.2, 20);
+}
+
+#[test]
+fn into_struct() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test {
+        name: String,
+    }
+
+    let test: Test = Test::builder().name("Awiteb").into();
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
+
+#[test]
+fn value_closure_reading_arguments() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test {
+        #[impl_new(value = |name| name.to_lowercase())]
+        slug: String,
+        name: String,
+    }
+
+    let test = Test::builder().name("Awiteb").build();
+    assert_eq!(test.slug, "awiteb".to_owned());
+    assert_eq!(test.name, "Awiteb".to_owned());
+
+    let test = Test::builder().slug("custom").name("Awiteb").build();
+    assert_eq!(test.slug, "custom".to_owned());
+}
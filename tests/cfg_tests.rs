@@ -0,0 +1,82 @@
+// The compiler removes the fields disabled with `#[cfg(...)]` before the derive runs, so they are
+// already left out of the generated code. These tests guard that behavior.
+
+#[test]
+fn cfg_fields() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[cfg(not(test))]
+        metrics: u64,
+        #[cfg(test)]
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn cfg_fields_with_options() {
+    fn not_empty(value: &str) -> Result<(), &'static str> {
+        if value.is_empty() {
+            Err("empty")
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(impl_new::New)]
+    struct Test {
+        #[cfg(test)]
+        #[impl_new(validate = not_empty, get)]
+        name: String,
+        #[cfg(not(test))]
+        #[impl_new(validate = not_empty, get)]
+        label: String,
+        #[cfg(test)]
+        #[impl_new(default = 8080, with)]
+        port: u16,
+        #[cfg(not(test))]
+        #[impl_new(default, with)]
+        metrics: u64,
+    }
+
+    let test = Test::try_new("Awiteb").unwrap().with_port(443u16);
+    assert_eq!(test.name(), "Awiteb");
+    assert_eq!(test.port, 443);
+    assert!(Test::try_new("").is_err());
+}
+
+#[test]
+fn cfg_variant_fields() {
+    #[derive(impl_new::New, Debug, PartialEq)]
+    enum Event {
+        Click {
+            x: u32,
+            #[cfg(not(test))]
+            y: u32,
+        },
+    }
+
+    assert_eq!(Event::new_click(1u32), Event::Click { x: 1 });
+}
+
+#[test]
+fn cfg_unnamed_fields() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[cfg(test)]
+        #[impl_new(name = "age")]
+        u8,
+        #[cfg(not(test))]
+        #[impl_new(name = "metrics")]
+        u64,
+    );
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, 20);
+}
@@ -0,0 +1,81 @@
+#[test]
+fn const_function() {
+    #[derive(impl_new::New)]
+    #[impl_new(const)]
+    struct Test {
+        name: &'static str,
+        port: u16,
+    }
+
+    const TEST: Test = Test::new("localhost", 8080);
+    assert_eq!(TEST.name, "localhost");
+    assert_eq!(TEST.port, 8080);
+}
+
+#[test]
+fn const_static_item() {
+    #[derive(impl_new::New)]
+    #[impl_new(const, fn_name = "from_parts")]
+    struct Test(
+        #[impl_new(name = "id")] u64,
+        #[impl_new(name = "name")] &'static str,
+    );
+
+    static TEST: Test = Test::from_parts(1, "Awiteb");
+    assert_eq!(TEST.0, 1);
+    assert_eq!(TEST.1, "Awiteb");
+}
+
+#[test]
+fn const_constructors() {
+    #[derive(impl_new::New)]
+    #[impl_new(
+        const,
+        constructor(name = "new"),
+        constructor(name = "with_port", args(port))
+    )]
+    struct Test {
+        #[impl_new(into = false)]
+        port: u16,
+    }
+
+    const TEST: Test = Test::new(80);
+    const OTHER: Test = Test::with_port(443);
+    assert_eq!(TEST.port, 80);
+    assert_eq!(OTHER.port, 443);
+}
+
+#[test]
+fn const_value_option() {
+    const DEFAULT_PORT: u16 = 8080;
+
+    #[derive(impl_new::New)]
+    #[impl_new(const)]
+    struct Test {
+        host: &'static str,
+        #[impl_new(value = DEFAULT_PORT)]
+        port: u16,
+        #[impl_new(value = || 3)]
+        retries: u8,
+    }
+
+    const TEST: Test = Test::new("localhost");
+    assert_eq!(TEST.host, "localhost");
+    assert_eq!(TEST.port, 8080);
+    assert_eq!(TEST.retries, 3);
+}
+
+#[test]
+fn const_default_value_option() {
+    #[derive(impl_new::New)]
+    #[impl_new(const)]
+    struct Test {
+        host: &'static str,
+        #[impl_new(default = 8080)]
+        port: u16,
+    }
+
+    const TEST: Test = Test::new("localhost");
+    assert_eq!(TEST.host, "localhost");
+    assert_eq!(TEST.port, 8080);
+}
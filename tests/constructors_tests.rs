@@ -0,0 +1,107 @@
+#[test]
+fn minimal_and_full_constructors() {
+    #[derive(impl_new::New)]
+    #[impl_new(
+        constructor(name = "new", args(name, age)),
+        constructor(name = "with_all")
+    )]
+    struct Test {
+        name: String,
+        age: u8,
+        #[impl_new(default)]
+        is_admin: bool,
+        #[impl_new(value = || 42)]
+        score: usize,
+    }
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+    assert!(!test.is_admin);
+    assert_eq!(test.score, 42);
+
+    let test = Test::with_all("Awiteb", 20, true, 7usize);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+    assert!(test.is_admin);
+    assert_eq!(test.score, 7);
+}
+
+#[test]
+fn args_order() {
+    #[derive(impl_new::New)]
+    #[impl_new(constructor(name = "from_age", args(age, name)))]
+    struct Test {
+        name: String,
+        age: u8,
+    }
+
+    let test = Test::from_age(20, "Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn args_with_name_option() {
+    // Clippy sees the repeated `username` argument as a duplicated attribute.
+    #[allow(clippy::duplicated_attributes)]
+    #[derive(impl_new::New)]
+    #[impl_new(constructor(name = "new", args(username)))]
+    #[impl_new(constructor(name = "with_age", args(username, age)))]
+    struct Test {
+        #[impl_new(name = "username")]
+        name: String,
+        #[impl_new(default)]
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 0);
+
+    let test = Test::with_age("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn empty_args() {
+    #[derive(impl_new::New)]
+    #[impl_new(constructor(name = "empty", args()), constructor(name = "new"))]
+    struct Test {
+        #[impl_new(default)]
+        name: String,
+        #[impl_new(value = || 20)]
+        age: u8,
+    }
+
+    let test = Test::empty();
+    assert_eq!(test.name, String::new());
+    assert_eq!(test.age, 20);
+
+    let test = Test::new("Awiteb", 30);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 30);
+}
+
+#[test]
+fn tuple_struct_constructors() {
+    #[derive(impl_new::New)]
+    #[impl_new(constructor(name = "new", args(name)), constructor(name = "with_all"))]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(default)] u8,
+        #[impl_new(value = || true)] bool,
+    );
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, 0);
+    assert!(test.2);
+
+    // Unnamed fields without the `name` option can't be arguments.
+    let test = Test::with_all("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, 0);
+    assert!(test.2);
+}
@@ -0,0 +1,129 @@
+#[test]
+fn with_doc_option() {
+    #[derive(impl_new::New)]
+    #[impl_new(doc = "Creates a new user with the given name.")]
+    struct Test {
+        name: String,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
+
+#[test]
+fn with_doc_option_tuple_struct() {
+    #[derive(impl_new::New)]
+    #[impl_new(doc = "Creates a new user with the given name.")]
+    struct Test(#[impl_new(name = "name")] String);
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+}
+
+#[test]
+fn with_doc_append_option() {
+    #[derive(impl_new::New)]
+    #[impl_new(doc_append = "The name is trimmed by the caller.")]
+    struct Test {
+        /// The name of the user.
+        ///
+        /// It's shown in the profile.
+        name: String,
+        #[impl_new(value = |name| name.len())]
+        length: usize,
+        #[impl_new(default = vec![1, 2])]
+        ids: Vec<u8>,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.length, 6);
+    assert_eq!(test.ids, vec![1, 2]);
+}
+
+#[test]
+fn with_doc_and_doc_append_options() {
+    #[derive(impl_new::New)]
+    #[impl_new(
+        doc = "Creates a new user with the given name.",
+        doc_append = "The name is trimmed by the caller."
+    )]
+    struct Test(
+        /// The name of the user.
+        #[impl_new(name = "name")]
+        String,
+    );
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+}
+
+#[test]
+fn inherited_visibility() {
+    #[deny(unreachable_pub)]
+    mod inner {
+        #[derive(impl_new::New)]
+        pub(crate) struct Test {
+            pub(crate) name: String,
+        }
+    }
+
+    let test = inner::Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
+
+#[test]
+fn with_vis_option() {
+    mod inner {
+        #[derive(impl_new::New)]
+        #[impl_new(vis = "pub(crate)")]
+        pub struct Test {
+            pub name: String,
+        }
+    }
+
+    let test = inner::Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
+
+#[test]
+fn with_vis_option_tuple_struct() {
+    mod inner {
+        #[derive(impl_new::New)]
+        #[impl_new(vis = "pub(super)")]
+        pub(crate) struct Test(#[impl_new(name = "name")] pub(crate) String);
+    }
+
+    let test = inner::Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+}
+
+#[test]
+fn with_fn_name_option() {
+    #[derive(impl_new::New)]
+    #[impl_new(fn_name = "with_parts")]
+    struct Test {
+        name: String,
+        age: u8,
+    }
+
+    impl Test {
+        fn new() -> Self {
+            Self::with_parts("Awiteb", 20)
+        }
+    }
+
+    let test = Test::new();
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn with_fn_name_option_tuple_struct() {
+    #[derive(impl_new::New)]
+    #[impl_new(fn_name = "from_parts")]
+    struct Test(#[impl_new(name = "name")] String);
+
+    let test = Test::from_parts("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+}
@@ -0,0 +1,119 @@
+#[test]
+fn variant_constructors() {
+    #[derive(Debug, PartialEq, impl_new::New)]
+    enum Shape {
+        Circle {
+            radius: f64,
+        },
+        Rect(
+            #[impl_new(name = "width")] f64,
+            #[impl_new(name = "height")] f64,
+        ),
+        Empty,
+    }
+
+    assert_eq!(Shape::new_circle(1.5), Shape::Circle { radius: 1.5 });
+    assert_eq!(Shape::new_rect(2.0, 3.0), Shape::Rect(2.0, 3.0));
+    assert_eq!(Shape::new_empty(), Shape::Empty);
+}
+
+#[test]
+fn variant_snake_case_names() {
+    #[derive(Debug, PartialEq, impl_new::New)]
+    enum Message {
+        HTTPRequest { url: String },
+        NotFound2 { path: String },
+    }
+
+    assert_eq!(
+        Message::new_http_request("/"),
+        Message::HTTPRequest {
+            url: "/".to_owned()
+        }
+    );
+    assert_eq!(
+        Message::new_not_found2("/"),
+        Message::NotFound2 {
+            path: "/".to_owned()
+        }
+    );
+}
+
+#[test]
+fn variant_field_options() {
+    #[derive(Debug, PartialEq, impl_new::New)]
+    enum Error {
+        Io {
+            #[impl_new(name = "error_message")]
+            message: String,
+            #[impl_new(default)]
+            code: i32,
+        },
+        Parse(
+            #[impl_new(name = "line")] usize,
+            #[impl_new(value = || "unexpected token".to_owned())] String,
+        ),
+    }
+
+    assert_eq!(
+        Error::new_io("denied"),
+        Error::Io {
+            message: "denied".to_owned(),
+            code: 0
+        }
+    );
+    assert_eq!(
+        Error::new_parse(4usize),
+        Error::Parse(4, "unexpected token".to_owned())
+    );
+}
+
+#[test]
+fn variant_fn_name_and_skip() {
+    #[derive(Debug, PartialEq, impl_new::New)]
+    enum Shape {
+        #[impl_new(fn_name = "circle")]
+        Circle { radius: f64 },
+        #[impl_new(skip)]
+        #[allow(dead_code)]
+        Point,
+    }
+
+    assert_eq!(Shape::circle(1.5), Shape::Circle { radius: 1.5 });
+}
+
+#[test]
+fn generic_enum_with_struct_options() {
+    mod inner {
+        #[derive(Debug, PartialEq, impl_new::New)]
+        #[impl_new(vis = "pub(crate)", into = false)]
+        pub enum Either<L, R> {
+            Left(#[impl_new(name = "value")] L),
+            Right(#[impl_new(name = "value")] R),
+        }
+    }
+
+    assert_eq!(
+        inner::Either::<u8, String>::new_left(1),
+        inner::Either::Left(1)
+    );
+    assert_eq!(
+        inner::Either::<u8, String>::new_right("Awiteb".to_owned()),
+        inner::Either::Right("Awiteb".to_owned())
+    );
+}
+
+#[test]
+fn const_enum() {
+    #[derive(Debug, PartialEq, impl_new::New)]
+    #[impl_new(const)]
+    enum Limit {
+        Max { value: u32 },
+        None,
+    }
+
+    const LIMIT: Limit = Limit::new_max(10);
+    const NONE: Limit = Limit::new_none();
+    assert_eq!(LIMIT, Limit::Max { value: 10 });
+    assert_eq!(NONE, Limit::None);
+}
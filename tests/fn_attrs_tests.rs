@@ -0,0 +1,96 @@
+#[test]
+fn fn_attrs() {
+    #[derive(impl_new::New)]
+    #[impl_new(fn_attrs(inline, track_caller, doc(hidden)))]
+    struct Test {
+        name: String,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+#[allow(deprecated)]
+fn fn_attrs_deprecated() {
+    #[derive(impl_new::New)]
+    #[impl_new(fn_attrs(deprecated(note = "Use `Test::builder` instead.")))]
+    struct Test {
+        name: String,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
+
+#[test]
+fn fn_attrs_must_use() {
+    #[deny(unused_attributes)]
+    mod inner {
+        #[derive(impl_new::New)]
+        #[impl_new(fn_attrs(must_use = "The created instance is not used."))]
+        pub(crate) struct Test {
+            pub(crate) name: String,
+        }
+    }
+
+    let test = inner::Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
+
+#[test]
+fn fn_attrs_constructors_and_variants() {
+    #[derive(impl_new::New)]
+    #[impl_new(
+        constructor(name = "new", args(name)),
+        constructor(name = "with_age"),
+        fn_attrs(inline)
+    )]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        age: u8,
+    }
+
+    #[derive(impl_new::New, Debug, PartialEq)]
+    #[impl_new(fn_attrs(inline))]
+    enum Event {
+        Click { x: u32 },
+        Close,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 0);
+    assert_eq!(Test::with_age("Awiteb", 20).age, 20);
+    assert_eq!(Event::new_click(1u32), Event::Click { x: 1 });
+    assert_eq!(Event::new_close(), Event::Close);
+}
+
+#[test]
+fn fn_attrs_fallible() {
+    #[derive(impl_new::New)]
+    #[impl_new(try_new, fn_attrs(inline))]
+    struct Test {
+        #[impl_new(try_into)]
+        port: u16,
+    }
+
+    assert_eq!(Test::try_new(8080u32).unwrap().port, 8080);
+    assert!(Test::try_new(70000u32).is_err());
+}
+
+#[test]
+#[deny(unused_must_use)]
+fn must_use_false() {
+    #[derive(impl_new::New)]
+    #[impl_new(must_use = false)]
+    struct Test {
+        name: String,
+    }
+
+    Test::new("Awiteb");
+    assert_eq!(Test::new("Awiteb").name, "Awiteb".to_owned());
+}
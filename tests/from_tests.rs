@@ -0,0 +1,131 @@
+#[test]
+fn from_tuple() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_tuple)]
+    struct Test {
+        name: String,
+        age: u8,
+        #[impl_new(default)]
+        is_admin: bool,
+    }
+
+    let test = Test::from(("Awiteb".to_owned(), 20));
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+    assert!(!test.is_admin);
+
+    let tests: Vec<Test> = vec![("Awiteb".to_owned(), 20), ("Bob".to_owned(), 30)]
+        .into_iter()
+        .map(Test::from)
+        .collect();
+    assert_eq!(tests[1].name, "Bob".to_owned());
+    assert_eq!(tests[1].age, 30);
+}
+
+#[test]
+fn from_tuple_single_argument() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_tuple)]
+    struct Test(#[impl_new(name = "name")] String);
+
+    let test: Test = ("Awiteb".to_owned(),).into();
+    assert_eq!(test.0, "Awiteb".to_owned());
+}
+
+#[test]
+fn from_tuple_generics() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_tuple)]
+    struct Test<'a, T: Clone> {
+        name: &'a str,
+        data: T,
+    }
+
+    let test = Test::from(("Awiteb", 42u8));
+    assert_eq!(test.name, "Awiteb");
+    assert_eq!(test.data, 42);
+}
+
+#[test]
+fn from_tuple_fn_name() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_tuple, fn_name = "create")]
+    struct Test {
+        name: String,
+        age: u8,
+    }
+
+    let test = Test::from(("Awiteb".to_owned(), 20));
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn from_arg() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_arg)]
+    struct Test {
+        name: String,
+        #[impl_new(value = |name| name.len())]
+        length: usize,
+    }
+
+    let test = Test::from("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.length, 6);
+
+    let tests: Vec<Test> = vec!["Awiteb", "Bob"].into_iter().map(Test::from).collect();
+    assert_eq!(tests[1].name, "Bob".to_owned());
+}
+
+#[test]
+fn from_arg_into_false() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_arg, into = false)]
+    struct Test<T> {
+        data: T,
+    }
+
+    let test = Test::from(42u8);
+    assert_eq!(test.data, 42);
+}
+
+#[test]
+fn from_arg_generics() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_arg)]
+    struct Wrap<T> {
+        inner: T,
+    }
+
+    #[derive(impl_new::New)]
+    #[impl_new(from_arg)]
+    struct Named<T> {
+        name: String,
+        #[impl_new(default)]
+        data: Option<T>,
+    }
+
+    let wrap = Wrap::from(42u8);
+    assert_eq!(wrap.inner, 42);
+    let wraps: Vec<Wrap<&str>> = vec!["Bob"].into_iter().map(Wrap::from).collect();
+    assert_eq!(wraps[0].inner, "Bob");
+
+    let named: Named<u8> = Named::from("Awiteb");
+    assert_eq!(named.name, "Awiteb".to_owned());
+    assert_eq!(named.data, None);
+}
+
+#[test]
+fn from_tuple_and_from_arg() {
+    #[derive(impl_new::New)]
+    #[impl_new(from_tuple, from_arg, into = false)]
+    struct Test {
+        port: u16,
+    }
+
+    let test = Test::from((8080,));
+    assert_eq!(test.port, 8080);
+    let test = Test::from(8080);
+    assert_eq!(test.port, 8080);
+}
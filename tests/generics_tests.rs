@@ -0,0 +1,92 @@
+use std::fmt::Debug;
+
+#[test]
+fn type_generic() {
+    #[derive(impl_new::New)]
+    struct Test<T> {
+        data: T,
+    }
+
+    let test = Test::<u8>::new(42u8);
+    assert_eq!(test.data, 42);
+}
+
+#[test]
+fn type_generic_with_bounds() {
+    #[derive(impl_new::New)]
+    struct Test<T: Clone + Debug, U = String> {
+        data: T,
+        other: U,
+    }
+
+    let test: Test<u8> = Test::new(42u8, "Awiteb");
+    assert_eq!(test.data, 42);
+    assert_eq!(test.other, "Awiteb".to_owned());
+}
+
+#[test]
+fn lifetime_generic() {
+    #[derive(impl_new::New)]
+    struct Test<'a> {
+        name: &'a str,
+    }
+
+    let name = String::from("Awiteb");
+    let test = Test::new(name.as_str());
+    assert_eq!(test.name, "Awiteb");
+}
+
+#[test]
+fn const_generic() {
+    #[derive(impl_new::New)]
+    struct Test<const N: usize> {
+        data: [u8; N],
+    }
+
+    let test = Test::new([1, 2, 3]);
+    assert_eq!(test.data, [1, 2, 3]);
+}
+
+#[test]
+fn where_clause() {
+    #[derive(impl_new::New)]
+    struct Test<'a, T>
+    where
+        T: Debug + ?Sized,
+    {
+        data: &'a T,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.data, "Awiteb");
+}
+
+#[test]
+fn generics_with_options() {
+    #[derive(impl_new::New)]
+    struct Test<'a, T: Default, const N: usize> {
+        #[impl_new(name = "slice")]
+        data: &'a [u8; N],
+        #[impl_new(default)]
+        other: T,
+    }
+
+    let test: Test<'_, String, 2> = Test::new(&[1, 2]);
+    assert_eq!(test.data, &[1, 2]);
+    assert_eq!(test.other, String::new());
+}
+
+#[test]
+fn tuple_struct_generics() {
+    #[derive(impl_new::New)]
+    struct Test<'a, T>(
+        #[impl_new(name = "name")] &'a str,
+        #[impl_new(name = "data")] T,
+    )
+    where
+        T: Clone;
+
+    let test: Test<u8> = Test::new("Awiteb", 42u8);
+    assert_eq!(test.0, "Awiteb");
+    assert_eq!(test.1, 42);
+}
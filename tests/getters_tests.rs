@@ -0,0 +1,84 @@
+#[test]
+fn field_getters() {
+    mod inner {
+        #[derive(impl_new::New)]
+        pub struct Test {
+            #[impl_new(get)]
+            name: String,
+            #[impl_new(get_copy, get_mut)]
+            age: u8,
+            #[impl_new(get_clone)]
+            tags: Vec<String>,
+            #[impl_new(default)]
+            _private: bool,
+        }
+    }
+
+    let mut test = inner::Test::new("Awiteb", 20, vec!["rust".to_owned()]);
+    assert_eq!(test.name(), "Awiteb");
+    assert_eq!(test.age(), 20);
+    *test.age_mut() += 1;
+    assert_eq!(test.age(), 21);
+    assert_eq!(test.tags(), vec!["rust".to_owned()]);
+}
+
+#[test]
+fn struct_getters() {
+    #[derive(impl_new::New)]
+    #[impl_new(get, get_mut)]
+    struct Test {
+        name: String,
+        #[impl_new(get_copy)]
+        age: u8,
+    }
+
+    let mut test = Test::new("Awiteb", 20);
+    assert_eq!(test.name(), "Awiteb");
+    assert_eq!(test.age(), 20);
+    test.name_mut().push('!');
+    assert_eq!(test.name(), "Awiteb!");
+    *test.age_mut() = 30;
+    assert_eq!(test.age(), 30);
+}
+
+#[test]
+fn renamed_field_getters() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(name = "username", get)]
+        name: String,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.username(), "Awiteb");
+}
+
+#[test]
+fn tuple_struct_getters() {
+    #[derive(impl_new::New)]
+    #[impl_new(get)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(name = "age", get_copy)] u8,
+        #[impl_new(default)] bool,
+    );
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name(), "Awiteb");
+    assert_eq!(test.age(), 20);
+    assert!(!test.2);
+}
+
+#[test]
+fn generic_getters() {
+    #[derive(impl_new::New)]
+    #[impl_new(get)]
+    struct Test<'a, T> {
+        name: &'a str,
+        data: T,
+    }
+
+    let test: Test<u8> = Test::new("Awiteb", 42u8);
+    assert_eq!(*test.name(), "Awiteb");
+    assert_eq!(*test.data(), 42);
+}
@@ -0,0 +1,70 @@
+#[test]
+fn impl_default() {
+    const DEFAULT_PORT: u16 = 8080;
+
+    #[derive(impl_new::New)]
+    #[impl_new(impl_default)]
+    struct Test {
+        #[impl_new(value = || "localhost".to_owned())]
+        host: String,
+        #[impl_new(default = DEFAULT_PORT)]
+        port: u16,
+        #[impl_new(default)]
+        paths: Vec<String>,
+    }
+
+    let test = Test::default();
+    assert_eq!(test.host, "localhost".to_owned());
+    assert_eq!(test.port, 8080);
+    assert!(test.paths.is_empty());
+
+    let new = Test::new();
+    assert_eq!(new.host, test.host);
+    assert_eq!(new.port, test.port);
+    assert_eq!(new.paths, test.paths);
+}
+
+#[test]
+fn impl_default_tuple_struct() {
+    #[derive(impl_new::New)]
+    #[impl_new(impl_default)]
+    struct Test(#[impl_new(value = 42)] u8, #[impl_new(default)] [u8; 2]);
+
+    let test = Test::default();
+    assert_eq!(test.0, 42);
+    assert_eq!(test.1, [0, 0]);
+}
+
+#[test]
+fn impl_default_generics() {
+    #[derive(impl_new::New)]
+    #[impl_new(impl_default)]
+    struct Test<T: Default> {
+        #[impl_new(default)]
+        data: T,
+        #[impl_new(value = true)]
+        is_set: bool,
+    }
+
+    let test: Test<String> = Test::default();
+    assert_eq!(test.data, String::new());
+    assert!(test.is_set);
+}
+
+#[test]
+fn impl_default_with_constructors() {
+    #[derive(impl_new::New)]
+    #[impl_new(
+        impl_default,
+        constructor(name = "new", args()),
+        constructor(name = "with_port", args(port))
+    )]
+    struct Test {
+        #[impl_new(default = 80)]
+        port: u16,
+    }
+
+    assert_eq!(Test::default().port, 80);
+    assert_eq!(Test::new().port, 80);
+    assert_eq!(Test::with_port(443u16).port, 443);
+}
@@ -0,0 +1,78 @@
+#[test]
+fn field_into_false() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(into = false)]
+        age: u64,
+    }
+
+    // Without `impl Into<u64>` the literal type is inferred.
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn struct_into_false() {
+    #[derive(impl_new::New)]
+    #[impl_new(into = false)]
+    struct Test {
+        name: String,
+        age: u8,
+        callback: Callback,
+    }
+
+    type Callback = fn(u8) -> u8;
+
+    let new: fn(String, u8, Callback) -> Test = Test::new;
+    let test = new("Awiteb".to_owned(), 20, |age| age + 1);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!((test.callback)(test.age), 21);
+}
+
+#[test]
+fn field_overrides_struct_into() {
+    #[derive(impl_new::New)]
+    #[impl_new(into = false)]
+    struct Test {
+        #[impl_new(into)]
+        name: String,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn tuple_struct_into_false() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(name = "closure", into = false)] Box<dyn Fn(u8) -> u8>,
+    );
+
+    let test = Test::new("Awiteb", Box::new(|n| n * 2));
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!((test.1)(21), 42);
+}
+
+#[test]
+fn into_false_with_default_and_value() {
+    #[derive(impl_new::New)]
+    #[impl_new(into = false)]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        age: u8,
+        #[impl_new(value = || true)]
+        is_admin: bool,
+    }
+
+    let test = Test::new("Awiteb".to_owned());
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 0);
+    assert!(test.is_admin);
+}
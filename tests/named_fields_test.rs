@@ -182,3 +182,244 @@ fn with_self_associated_function_value_option() {
     assert_eq!(test.name, "Awiteb".to_owned());
     assert_eq!(test.data, "2023-01-01".to_owned());
 }
+
+#[test]
+fn with_plain_value_option() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(value = 42)]
+        age: usize,
+        #[impl_new(value = None)]
+        email: Option<String>,
+        #[impl_new(value = u8::MAX)]
+        level: u8,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 42);
+    assert_eq!(test.email, None);
+    assert_eq!(test.level, u8::MAX);
+}
+
+#[test]
+fn with_string_literal_value_option() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(value = "localhost")]
+        host: &'static str,
+        #[impl_new(value = 'a')]
+        level: char,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.host, "localhost");
+    assert_eq!(test.level, 'a');
+}
+
+#[test]
+fn with_constant_value_option() {
+    const DEFAULT_PORT: u16 = 8080;
+
+    #[derive(impl_new::New)]
+    struct Test {
+        host: String,
+        #[impl_new(value = DEFAULT_PORT)]
+        port: u16,
+    }
+
+    let test = Test::new("localhost");
+    assert_eq!(test.host, "localhost".to_owned());
+    assert_eq!(test.port, 8080);
+}
+
+#[test]
+fn with_function_path_value_option() {
+    fn get_age() -> usize {
+        42
+    }
+
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(value = get_age)]
+        age: usize,
+        #[impl_new(value = Self::some_date)]
+        data: String,
+        #[impl_new(value = String::new)]
+        name: String,
+    }
+
+    impl Test {
+        fn some_date() -> String {
+            "2023-01-01".to_owned()
+        }
+    }
+
+    let test = Test::new();
+    assert_eq!(test.age, 42);
+    assert_eq!(test.data, "2023-01-01".to_owned());
+    assert_eq!(test.name, String::new());
+}
+
+#[test]
+fn with_default_option_on_non_path_types() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        bytes: [u8; 4],
+        #[impl_new(default)]
+        pair: (u8, String),
+        #[impl_new(default)]
+        label: &'static str,
+        #[impl_new(default)]
+        email: Option<String>,
+        #[impl_new(default)]
+        item: <Vec<u8> as IntoIterator>::Item,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.bytes, [0; 4]);
+    assert_eq!(test.pair, (0, String::new()));
+    assert_eq!(test.label, "");
+    assert_eq!(test.email, None);
+    assert_eq!(test.item, 0);
+}
+
+#[test]
+fn with_default_value_option() {
+    const DEFAULT_PORT: u16 = 8080;
+
+    #[derive(impl_new::New)]
+    struct Test {
+        host: String,
+        #[impl_new(default = DEFAULT_PORT)]
+        port: u16,
+        #[impl_new(default = [1, 2, 3])]
+        bytes: [u8; 3],
+        #[impl_new(default = String::new)]
+        path: String,
+    }
+
+    let test = Test::new("localhost");
+    assert_eq!(test.host, "localhost".to_owned());
+    assert_eq!(test.port, 8080);
+    assert_eq!(test.bytes, [1, 2, 3]);
+    assert_eq!(test.path, String::new());
+}
+
+#[test]
+fn with_value_closure_reading_arguments() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(value = |name| format!("{}-id", name))]
+        id: String,
+        name: String,
+        #[impl_new(value = |name: &String, age| name.len() + *age as usize)]
+        score: usize,
+        age: u8,
+        #[impl_new(default = |age| *age >= 18)]
+        is_adult: bool,
+    }
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.id, "Awiteb-id".to_owned());
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.score, 26);
+    assert_eq!(test.age, 20);
+    assert!(test.is_adult);
+}
+
+#[test]
+fn with_unit_path_value_option() {
+    #[derive(Debug, PartialEq)]
+    struct Marker;
+
+    #[derive(Debug, PartialEq)]
+    enum Role {
+        Admin,
+    }
+
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(value = Marker)]
+        marker: Marker,
+        #[impl_new(value = Role::Admin)]
+        role: Role,
+    }
+
+    let test = Test::new();
+    assert_eq!(test.marker, Marker);
+    assert_eq!(test.role, Role::Admin);
+}
+
+#[test]
+fn with_value_closure_early_return() {
+    #[derive(impl_new::New)]
+    struct Test {
+        is_admin: bool,
+        #[impl_new(value = |is_admin| {
+            if *is_admin {
+                return 10;
+            }
+            1
+        })]
+        level: u8,
+        #[impl_new(value = || {
+            let level: Option<u8> = None;
+            Some(level? + 1)
+        })]
+        next_level: Option<u8>,
+    }
+
+    let test = Test::new(true);
+    assert!(test.is_admin);
+    assert_eq!(test.level, 10);
+    assert_eq!(test.next_level, None);
+    assert_eq!(Test::new(false).level, 1);
+}
+
+#[test]
+fn with_value_closure_question_mark_in_try_new() {
+    #[derive(impl_new::New)]
+    #[impl_new(try_new)]
+    struct Test {
+        #[impl_new(try_into)]
+        port: u16,
+        #[impl_new(value = |port| -> Result<String, std::num::ParseIntError> {
+            Ok(format!("{}", "1".parse::<u16>()? + *port))
+        })]
+        label: Result<String, std::num::ParseIntError>,
+    }
+
+    let test = Test::try_new(8080u32).unwrap();
+    assert_eq!(test.port, 8080);
+    assert_eq!(test.label, Ok("8081".to_owned()));
+}
+
+#[test]
+fn with_multiple_attributes() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(name = "username")]
+        #[impl_new(into = false)]
+        name: String,
+        #[impl_new(default = 8080)]
+        #[impl_new(with)]
+        port: u16,
+        #[cfg_attr(all(), impl_new(value = true))]
+        #[impl_new(with)]
+        is_active: bool,
+    }
+
+    let test = Test::new("Awiteb".to_owned())
+        .with_port(443u16)
+        .with_is_active(false);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.port, 443);
+    assert!(!test.is_active);
+}
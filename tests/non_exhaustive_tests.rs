@@ -0,0 +1,61 @@
+#[test]
+fn non_exhaustive_struct() {
+    #[derive(impl_new::New)]
+    #[non_exhaustive]
+    pub struct Test {
+        pub name: String,
+        pub age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn non_exhaustive_tuple_struct() {
+    #[derive(impl_new::New)]
+    #[non_exhaustive]
+    pub struct Test(#[impl_new(name = "name")] pub String);
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+}
+
+#[test]
+fn non_exhaustive_unit_struct() {
+    #[derive(impl_new::New, Debug, PartialEq)]
+    #[non_exhaustive]
+    pub struct Marker;
+
+    assert_eq!(Marker::new(), Marker);
+}
+
+#[test]
+fn non_exhaustive_variant() {
+    #[derive(impl_new::New, Debug, PartialEq)]
+    pub enum Event {
+        #[non_exhaustive]
+        Click {
+            x: u32,
+            y: u32,
+        },
+        Close,
+    }
+
+    assert_eq!(Event::new_click(1u32, 2u32), Event::Click { x: 1, y: 2 });
+    assert_eq!(Event::new_close(), Event::Close);
+}
+
+#[test]
+fn non_exhaustive_with_doc() {
+    #[derive(impl_new::New)]
+    #[impl_new(doc = "Creates a new test.")]
+    #[non_exhaustive]
+    pub struct Test {
+        pub name: String,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+}
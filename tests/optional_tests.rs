@@ -0,0 +1,133 @@
+#[test]
+fn optional_field() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(optional)]
+        email: Option<String>,
+    }
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.email, None);
+}
+
+#[test]
+fn optional_arg_field() {
+    #[derive(impl_new::New)]
+    #[impl_new(into = false)]
+    struct Test {
+        name: String,
+        #[impl_new(optional = "arg")]
+        email: Option<String>,
+        #[impl_new(optional = "arg")]
+        age: Option<u8>,
+    }
+
+    let test = Test::new("Awiteb".to_owned(), "awiteb@example.com".to_owned(), None);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.email, Some("awiteb@example.com".to_owned()));
+    assert_eq!(test.age, None);
+
+    let test = Test::new("Awiteb".to_owned(), None, Some(20));
+    assert_eq!(test.email, None);
+    assert_eq!(test.age, Some(20));
+}
+
+#[test]
+fn optional_tuple_struct() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(optional)] Option<u8>,
+        #[impl_new(name = "email", optional = "arg")] Option<String>,
+    );
+
+    let test = Test::new("Awiteb", None);
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, None);
+    assert_eq!(test.2, None);
+}
+
+#[test]
+fn container_optional() {
+    #[derive(impl_new::New)]
+    #[impl_new(optional)]
+    struct Test {
+        name: String,
+        email: Option<String>,
+        #[impl_new(value = Some(20))]
+        age: Option<u8>,
+        #[impl_new(optional = "arg")]
+        phone: Option<String>,
+    }
+
+    let test = Test::new("Awiteb", None);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.email, None);
+    assert_eq!(test.age, Some(20));
+    assert_eq!(test.phone, None);
+}
+
+#[test]
+fn container_optional_tuple_struct() {
+    #[derive(impl_new::New)]
+    #[impl_new(optional)]
+    struct Test(#[impl_new(name = "name")] String, Option<u8>);
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, None);
+}
+
+#[test]
+fn container_optional_arg() {
+    #[derive(impl_new::New)]
+    #[impl_new(optional = "arg", into = false)]
+    struct Test {
+        name: String,
+        email: Option<String>,
+        #[impl_new(optional)]
+        phone: Option<String>,
+    }
+
+    let test = Test::new("Awiteb".to_owned(), "awiteb@example.com".to_owned());
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.email, Some("awiteb@example.com".to_owned()));
+    assert_eq!(test.phone, None);
+}
+
+#[test]
+fn const_optional() {
+    #[derive(impl_new::New)]
+    #[impl_new(const, optional)]
+    struct Test {
+        port: u16,
+        host: Option<&'static str>,
+    }
+
+    const TEST: Test = Test::new(8080);
+    assert_eq!(TEST.port, 8080);
+    assert_eq!(TEST.host, None);
+}
+
+#[test]
+fn builder_optional() {
+    #[derive(impl_new::New)]
+    #[impl_new(builder)]
+    struct Test {
+        name: String,
+        #[impl_new(optional)]
+        email: Option<String>,
+    }
+
+    let test = Test::builder().name("Awiteb").build();
+    assert_eq!(test.email, None);
+
+    let test = Test::builder()
+        .email("awiteb@example.com".to_owned())
+        .name("Awiteb")
+        .build();
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.email, Some("awiteb@example.com".to_owned()));
+}
@@ -0,0 +1,217 @@
+// Every ordering of argument, `default` and `value` fields, the arguments must keep their types.
+
+#[test]
+fn named_arg_default_value() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        id: u64,
+        #[impl_new(value = true)]
+        is_active: bool,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.id, 0);
+    assert!(test.is_active);
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn tuple_arg_default_value() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(default)] u64,
+        #[impl_new(value = true)] bool,
+        #[impl_new(name = "age")] u8,
+    );
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, 0);
+    assert!(test.2);
+    assert_eq!(test.3, 20);
+}
+
+#[test]
+fn named_arg_value_default() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(value = true)]
+        is_active: bool,
+        #[impl_new(default)]
+        id: u64,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert!(test.is_active);
+    assert_eq!(test.id, 0);
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn tuple_arg_value_default() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(value = true)] bool,
+        #[impl_new(default)] u64,
+        #[impl_new(name = "age")] u8,
+    );
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert!(test.1);
+    assert_eq!(test.2, 0);
+    assert_eq!(test.3, 20);
+}
+
+#[test]
+fn named_default_arg_value() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(default)]
+        id: u64,
+        name: String,
+        #[impl_new(value = true)]
+        is_active: bool,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.id, 0);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert!(test.is_active);
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn tuple_default_arg_value() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(default)] u64,
+        #[impl_new(name = "name")] String,
+        #[impl_new(value = true)] bool,
+        #[impl_new(name = "age")] u8,
+    );
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.0, 0);
+    assert_eq!(test.1, "Awiteb".to_owned());
+    assert!(test.2);
+    assert_eq!(test.3, 20);
+}
+
+#[test]
+fn named_default_value_arg() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(default)]
+        id: u64,
+        #[impl_new(value = true)]
+        is_active: bool,
+        name: String,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.id, 0);
+    assert!(test.is_active);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn tuple_default_value_arg() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(default)] u64,
+        #[impl_new(value = true)] bool,
+        #[impl_new(name = "name")] String,
+        #[impl_new(name = "age")] u8,
+    );
+
+    let test = Test::new("Awiteb", 20u8);
+    assert_eq!(test.0, 0);
+    assert!(test.1);
+    assert_eq!(test.2, "Awiteb".to_owned());
+    assert_eq!(test.3, 20);
+}
+
+#[test]
+fn named_value_arg_default() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(value = true)]
+        is_active: bool,
+        name: String,
+        #[impl_new(default)]
+        id: u64,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20u8);
+    assert!(test.is_active);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.id, 0);
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn tuple_value_arg_default() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(value = true)] bool,
+        #[impl_new(name = "name")] String,
+        #[impl_new(default)] u64,
+        #[impl_new(name = "age")] u8,
+    );
+
+    let test = Test::new("Awiteb", 20u8);
+    assert!(test.0);
+    assert_eq!(test.1, "Awiteb".to_owned());
+    assert_eq!(test.2, 0);
+    assert_eq!(test.3, 20);
+}
+
+#[test]
+fn named_value_default_arg() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(value = true)]
+        is_active: bool,
+        #[impl_new(default)]
+        id: u64,
+        name: String,
+        age: u8,
+    }
+
+    let test = Test::new("Awiteb", 20u8);
+    assert!(test.is_active);
+    assert_eq!(test.id, 0);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+}
+
+#[test]
+fn tuple_value_default_arg() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(value = true)] bool,
+        #[impl_new(default)] u64,
+        #[impl_new(name = "name")] String,
+        #[impl_new(name = "age")] u8,
+    );
+
+    let test = Test::new("Awiteb", 20u8);
+    assert!(test.0);
+    assert_eq!(test.1, 0);
+    assert_eq!(test.2, "Awiteb".to_owned());
+    assert_eq!(test.3, 20);
+}
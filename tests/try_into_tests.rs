@@ -0,0 +1,155 @@
+use std::convert::TryFrom;
+use std::error::Error;
+
+#[derive(Debug, PartialEq)]
+struct NonEmptyString(String);
+
+#[derive(Debug, PartialEq)]
+struct EmptyStringError;
+
+impl std::fmt::Display for EmptyStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the string is empty")
+    }
+}
+
+impl Error for EmptyStringError {}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = EmptyStringError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyStringError)
+        } else {
+            Ok(Self(value.to_owned()))
+        }
+    }
+}
+
+#[test]
+fn try_into_field() {
+    #[derive(Debug, impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(try_into)]
+        port: u16,
+    }
+
+    let test = Test::try_new("localhost", 8080u64).unwrap();
+    assert_eq!(test.name, "localhost".to_owned());
+    assert_eq!(test.port, 8080);
+
+    let err = Test::try_new("localhost", 70000u64).unwrap_err();
+    assert!(matches!(err, TestError::Port(_)));
+    assert!(err.to_string().starts_with("invalid `port` argument: "));
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn multiple_try_into_fields() {
+    #[derive(Debug, impl_new::New)]
+    struct Test {
+        #[impl_new(try_into)]
+        name: NonEmptyString,
+        #[impl_new(try_into, name = "user_age")]
+        age: u8,
+    }
+
+    let test = Test::try_new("Awiteb", 20u32).unwrap();
+    assert_eq!(test.name, NonEmptyString("Awiteb".to_owned()));
+    assert_eq!(test.age, 20);
+
+    assert!(matches!(Test::try_new("", 20u32), Err(TestError::Name(_))));
+    assert!(matches!(
+        Test::try_new("Awiteb", 256u32),
+        Err(TestError::UserAge(_))
+    ));
+}
+
+#[test]
+fn try_into_with_custom_error() {
+    #[derive(Debug, PartialEq)]
+    enum MyError {
+        Empty,
+        OutOfRange,
+    }
+
+    impl From<EmptyStringError> for MyError {
+        fn from(_: EmptyStringError) -> Self {
+            Self::Empty
+        }
+    }
+
+    impl From<std::num::TryFromIntError> for MyError {
+        fn from(_: std::num::TryFromIntError) -> Self {
+            Self::OutOfRange
+        }
+    }
+
+    #[derive(Debug, impl_new::New)]
+    #[impl_new(error = "MyError")]
+    struct Test(
+        #[impl_new(name = "name", try_into)] NonEmptyString,
+        #[impl_new(name = "age", try_into)] u8,
+    );
+
+    let test = Test::try_new("Awiteb", 20i64).unwrap();
+    assert_eq!(test.0, NonEmptyString("Awiteb".to_owned()));
+    assert_eq!(test.1, 20);
+
+    assert_eq!(Test::try_new("", 20i64).unwrap_err(), MyError::Empty);
+    assert_eq!(
+        Test::try_new("Awiteb", -1i64).unwrap_err(),
+        MyError::OutOfRange
+    );
+}
+
+#[test]
+fn try_new_option() {
+    #[derive(Debug, impl_new::New)]
+    #[impl_new(try_new, fn_name = "parse")]
+    struct Test {
+        name: String,
+    }
+
+    let test: Result<Test, TestError> = Test::parse("Awiteb");
+    assert_eq!(test.unwrap().name, "Awiteb".to_owned());
+}
+
+#[test]
+fn try_into_enum() {
+    #[derive(Debug, PartialEq, impl_new::New)]
+    enum Port {
+        Tcp {
+            #[impl_new(try_into)]
+            port: u16,
+        },
+        Udp {
+            #[impl_new(try_into)]
+            port: u16,
+        },
+        Any,
+    }
+
+    assert_eq!(Port::try_new_tcp(80u32).unwrap(), Port::Tcp { port: 80 });
+    assert_eq!(Port::try_new_udp(53u32).unwrap(), Port::Udp { port: 53 });
+    assert!(matches!(Port::try_new_udp(-1i32), Err(PortError::Port(_))));
+    assert_eq!(Port::new_any(), Port::Any);
+}
+
+#[test]
+fn value_closure_reading_try_into_argument() {
+    #[derive(impl_new::New)]
+    struct Test {
+        #[impl_new(value = |name| name.0.len())]
+        length: usize,
+        #[impl_new(try_into)]
+        name: NonEmptyString,
+    }
+
+    let test = Test::try_new("Awiteb").unwrap();
+    assert_eq!(test.length, 6);
+    assert_eq!(test.name, NonEmptyString("Awiteb".to_owned()));
+    assert!(Test::try_new("").is_err());
+}
@@ -0,0 +1,52 @@
+#[test]
+fn unit_struct() {
+    #[derive(impl_new::New, Debug, PartialEq)]
+    struct Marker;
+
+    assert_eq!(Marker::new(), Marker);
+}
+
+#[test]
+fn const_unit_struct() {
+    #[derive(impl_new::New, Debug, PartialEq)]
+    #[impl_new(const, fn_name = "create")]
+    struct Marker;
+
+    const MARKER: Marker = Marker::create();
+    assert_eq!(MARKER, Marker);
+}
+
+#[test]
+fn unit_struct_with_impl_default() {
+    #[derive(impl_new::New, Debug, PartialEq)]
+    #[impl_new(impl_default)]
+    struct Marker;
+
+    fn default_of<T: Default>() -> T {
+        T::default()
+    }
+
+    assert_eq!(default_of::<Marker>(), Marker);
+}
+
+#[test]
+fn generic_unit_struct() {
+    use std::marker::PhantomData;
+
+    #[derive(impl_new::New, Debug, PartialEq)]
+    struct Marker<T>(#[impl_new(default)] PhantomData<T>);
+
+    assert_eq!(Marker::<u8>::new(), Marker(PhantomData));
+}
+
+#[test]
+fn unit_variant() {
+    #[derive(impl_new::New, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Running(#[impl_new(name = "pid")] u32),
+    }
+
+    assert_eq!(State::new_idle(), State::Idle);
+    assert_eq!(State::new_running(1u32), State::Running(1));
+}
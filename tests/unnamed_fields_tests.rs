@@ -152,3 +152,48 @@ fn with_self_associated_function_value_option() {
     assert_eq!(test.0, "Awiteb".to_owned());
     assert_eq!(test.1, "Some Value".to_owned());
 }
+
+#[test]
+fn with_default_option_on_non_path_types() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(default)] [u8; 2],
+        #[impl_new(default)] (bool, usize),
+        #[impl_new(default = 20)] usize,
+    );
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, [0, 0]);
+    assert_eq!(test.2, (false, 0));
+    assert_eq!(test.3, 20);
+}
+
+#[test]
+fn with_value_closure_reading_arguments() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")] String,
+        #[impl_new(value = |name| name.len())] usize,
+    );
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.0, "Awiteb".to_owned());
+    assert_eq!(test.1, 6);
+}
+
+#[test]
+fn with_multiple_attributes() {
+    #[derive(impl_new::New)]
+    struct Test(
+        #[impl_new(name = "name")]
+        #[impl_new(get)]
+        String,
+        #[cfg_attr(all(), impl_new(default))] u8,
+    );
+
+    let test = Test::new("Awiteb");
+    assert_eq!(test.name(), "Awiteb");
+    assert_eq!(test.1, 0);
+}
@@ -0,0 +1,191 @@
+fn check_port(port: &u16) -> Result<(), &'static str> {
+    if *port == 0 {
+        Err("the port cannot be zero")
+    } else {
+        Ok(())
+    }
+}
+
+fn check_host(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        Err("the host cannot be empty".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn field_validate() {
+    #[derive(Debug, impl_new::New)]
+    struct Test {
+        #[impl_new(validate = check_host)]
+        host: String,
+        #[impl_new(validate = check_port)]
+        port: u16,
+    }
+
+    let test = Test::try_new("localhost", 8080u16).unwrap();
+    assert_eq!(test.host, "localhost".to_owned());
+    assert_eq!(test.port, 8080);
+
+    let err = Test::try_new("localhost", 0u16).unwrap_err();
+    assert!(matches!(err, TestError::Port(_)));
+    assert_eq!(
+        err.to_string(),
+        "invalid `port` argument: the port cannot be zero"
+    );
+    // The first error is returned.
+    assert!(matches!(Test::try_new("", 0u16), Err(TestError::Host(_))));
+}
+
+#[test]
+fn struct_validate() {
+    #[derive(Debug, impl_new::New)]
+    #[impl_new(validate = Self::check)]
+    struct Test {
+        min: u8,
+        max: u8,
+    }
+
+    impl Test {
+        fn check(&self) -> Result<(), &'static str> {
+            if self.min > self.max {
+                Err("min is greater than max")
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let test = Test::try_new(1u8, 2u8).unwrap();
+    assert_eq!((test.min, test.max), (1, 2));
+
+    let err = Test::try_new(2u8, 1u8).unwrap_err();
+    assert!(matches!(err, TestError::Invalid(_)));
+    assert_eq!(err.to_string(), "invalid `Test`: min is greater than max");
+}
+
+#[test]
+fn validate_all() {
+    #[derive(Debug, impl_new::New)]
+    #[impl_new(validate_all)]
+    struct Test(
+        #[impl_new(name = "host", validate = check_host)] String,
+        #[impl_new(name = "port", validate = check_port)] u16,
+    );
+
+    let test = Test::try_new("localhost", 80u16).unwrap();
+    assert_eq!(test.0, "localhost".to_owned());
+    assert_eq!(test.1, 80);
+
+    let errors = Test::try_new("", 0u16).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], TestError::Host(_)));
+    assert!(matches!(errors[1], TestError::Port(_)));
+}
+
+#[test]
+fn validate_with_try_into_and_custom_error() {
+    #[derive(Debug, PartialEq)]
+    enum MyError {
+        OutOfRange,
+        Invalid(&'static str),
+    }
+
+    impl From<std::num::TryFromIntError> for MyError {
+        fn from(_: std::num::TryFromIntError) -> Self {
+            Self::OutOfRange
+        }
+    }
+
+    impl From<&'static str> for MyError {
+        fn from(msg: &'static str) -> Self {
+            Self::Invalid(msg)
+        }
+    }
+
+    #[derive(Debug, impl_new::New)]
+    #[impl_new(error = "MyError")]
+    struct Test {
+        #[impl_new(try_into, validate = check_port)]
+        port: u16,
+    }
+
+    assert_eq!(Test::try_new(80u64).unwrap().port, 80);
+    assert_eq!(Test::try_new(70000u64).unwrap_err(), MyError::OutOfRange);
+    assert_eq!(
+        Test::try_new(0u64).unwrap_err(),
+        MyError::Invalid("the port cannot be zero")
+    );
+}
+
+#[test]
+fn validate_default_value() {
+    fn check_name(name: &str) -> Result<(), &'static str> {
+        if name.is_empty() {
+            Err("empty name")
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, impl_new::New)]
+    struct Test {
+        #[impl_new(default, validate = check_name)]
+        name: String,
+    }
+
+    let err = Test::try_new().unwrap_err();
+    assert!(matches!(err, TestError::Name(_)));
+    assert_eq!(err.to_string(), "invalid `name` argument: empty name");
+    assert!(Test::try_new().map(|test| test.name).is_err());
+}
+
+#[test]
+fn struct_validate_renamed_invalid_field() {
+    #[derive(Debug, impl_new::New)]
+    #[impl_new(validate = Self::check)]
+    struct Test {
+        #[impl_new(name = "invalid_port", validate = check_port)]
+        invalid: u16,
+    }
+
+    impl Test {
+        fn check(&self) -> Result<(), &'static str> {
+            if self.invalid == 8080 {
+                Err("reserved port")
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    assert_eq!(Test::try_new(443u16).unwrap().invalid, 443);
+    assert!(matches!(
+        Test::try_new(0u16),
+        Err(TestError::InvalidPort(_))
+    ));
+    assert!(matches!(Test::try_new(8080u16), Err(TestError::Invalid(_))));
+}
+
+#[test]
+fn validate_renamed_colliding_arguments() {
+    #[derive(Debug, impl_new::New)]
+    struct Test {
+        #[impl_new(validate = check_port)]
+        port_: u16,
+        #[impl_new(name = "fallback_port", validate = check_port)]
+        port: u16,
+    }
+
+    let test = Test::try_new(443u16, 80u16).unwrap();
+    assert_eq!((test.port_, test.port), (443, 80));
+    assert!(matches!(
+        Test::try_new(0u16, 80u16),
+        Err(TestError::Port(_))
+    ));
+    assert!(matches!(
+        Test::try_new(80u16, 0u16),
+        Err(TestError::FallbackPort(_))
+    ));
+}
@@ -0,0 +1,79 @@
+#[test]
+fn with_field() {
+    #[derive(impl_new::New)]
+    struct Test {
+        name: String,
+        #[impl_new(default, with)]
+        age: u8,
+        #[impl_new(value = true)]
+        is_active: bool,
+    }
+
+    let test = Test::new("Awiteb").with_age(20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+    assert!(test.is_active);
+}
+
+#[test]
+fn with_struct() {
+    #[derive(impl_new::New)]
+    #[impl_new(with)]
+    struct Test {
+        name: String,
+        #[impl_new(default)]
+        age: u8,
+        #[impl_new(value = || "Unknown".to_owned())]
+        country: String,
+        #[impl_new(optional)]
+        email: Option<String>,
+    }
+
+    let test = Test::new("Awiteb")
+        .with_country("Palestine")
+        .with_email("awiteb@example.com".to_owned())
+        .with_age(20);
+    assert_eq!(test.name, "Awiteb".to_owned());
+    assert_eq!(test.age, 20);
+    assert_eq!(test.country, "Palestine".to_owned());
+    assert_eq!(test.email, Some("awiteb@example.com".to_owned()));
+}
+
+#[test]
+fn with_into_false() {
+    #[derive(impl_new::New)]
+    #[impl_new(with, into = false)]
+    struct Test<T: Default> {
+        #[impl_new(default)]
+        data: T,
+    }
+
+    let test = Test::<String>::new().with_data("Awiteb".to_owned());
+    assert_eq!(test.data, "Awiteb".to_owned());
+}
+
+#[test]
+fn with_private_fields() {
+    mod inner {
+        #[derive(impl_new::New)]
+        pub struct Test {
+            name: String,
+            #[impl_new(default = 8080, with)]
+            port: u16,
+        }
+
+        impl Test {
+            pub fn port(&self) -> u16 {
+                self.port
+            }
+
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+        }
+    }
+
+    let test = inner::Test::new("localhost").with_port(443u16);
+    assert_eq!(test.name(), "localhost");
+    assert_eq!(test.port(), 443);
+}